@@ -0,0 +1,30 @@
+use aoc_utils::{day1, AocError, Input, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &Input) -> Result<Vec<i32>, AocError> {
+        day1::compute_elf_snack_weights(input)
+    }
+
+    fn part1(snacks: &Vec<i32>) -> i32 {
+        // Compute max value of array
+        *snacks.iter().max().expect("Should have a max")
+    }
+
+    fn part2(snacks: &Vec<i32>) -> i32 {
+        let mut snacks = snacks.clone();
+
+        // Sort the array
+        snacks.sort_unstable_by(|a, b| b.cmp(a));
+
+        // Sum the top three
+        snacks[0..3].iter().sum()
+    }
+}
+
+aoc_utils::example_tests!(Day1);
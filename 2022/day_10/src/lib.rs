@@ -0,0 +1,170 @@
+use aoc_utils::{
+    error::{next_token, parse_token},
+    AocError, Input, Solution,
+};
+
+pub struct Day10;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+fn instruction_cycles(instruction: Instruction) -> i64 {
+    match instruction {
+        Instruction::Noop => 1,
+        Instruction::Addx(_) => 2,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Processing {
+    instruction: Instruction,
+    left_cycles: i64,
+}
+
+fn process(instruction: Instruction, x: i64) -> i64 {
+    match instruction {
+        Instruction::Noop => x,
+        Instruction::Addx(y) => x + y,
+    }
+}
+
+struct Cpu<'a> {
+    x: i64,
+    cycle: i64,
+    executing: Option<Processing>,
+    program: std::slice::Iter<'a, Instruction>,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(program: &'a [Instruction]) -> Cpu<'a> {
+        Cpu {
+            x: 1,
+            cycle: 0,
+            executing: None,
+            program: program.iter(),
+        }
+    }
+
+    fn clock(&mut self) -> bool {
+        self.cycle += 1;
+        if let Some(cmd) = self.executing {
+            if cmd.left_cycles == 0 {
+                self.x = process(cmd.instruction, self.x);
+                self.executing = None;
+            }
+        }
+        if self.executing.is_none() {
+            let Some(next) = self.program.next() else {
+                return false;
+            };
+            self.executing = Some(Processing {
+                instruction: *next,
+                left_cycles: instruction_cycles(*next),
+            })
+        }
+        let mut cmd = self.executing.unwrap();
+        cmd.left_cycles -= 1;
+        self.executing = Some(cmd);
+        true
+    }
+
+    fn signal_strength(&self) -> i64 {
+        self.cycle * self.x
+    }
+}
+
+struct Screen {
+    width: usize,
+    row: usize,
+    col: usize,
+    res: String,
+}
+
+impl Screen {
+    fn new() -> Screen {
+        Screen {
+            width: 40,
+            row: 0,
+            col: 0,
+            res: String::new(),
+        }
+    }
+
+    fn clock(&mut self, cpu: &Cpu) {
+        let lit = self.col.abs_diff(cpu.x as usize) < 2;
+        self.res.push(match lit {
+            true => '#',
+            false => '.',
+        });
+        self.col += 1;
+        if self.col == self.width {
+            self.row += 1;
+            self.col = 0;
+            self.res.push('\n');
+        }
+    }
+}
+
+fn read_instruction(line: &str) -> Result<Instruction, AocError> {
+    let mut tokens = line.split(' ');
+    let cmd = next_token(line, &mut tokens, "instruction")?;
+    match cmd {
+        "noop" => Ok(Instruction::Noop),
+        "addx" => {
+            let value = next_token(line, &mut tokens, "addx value")?;
+            Ok(Instruction::Addx(parse_token(line, value, "addx value")?))
+        }
+        _ => Err(AocError::at(
+            line,
+            cmd,
+            format!("unknown instruction `{cmd}`"),
+        )),
+    }
+}
+
+fn read_program(input: &Input) -> Result<Vec<Instruction>, AocError> {
+    input.parse_lines(|line| {
+        read_instruction(line).map_err(|err| err.expecting("`addx <int>` or `noop`"))
+    })
+}
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> Result<Vec<Instruction>, AocError> {
+        read_program(input)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> i64 {
+        let mut cpu = Cpu::new(program);
+
+        let mut sum = 0;
+
+        while cpu.clock() {
+            let interesting = (cpu.cycle - 20) % 40 == 0;
+            if interesting {
+                sum += cpu.signal_strength();
+            }
+        }
+
+        sum
+    }
+
+    fn part2(program: &Vec<Instruction>) -> String {
+        let mut cpu = Cpu::new(program);
+        let mut screen = Screen::new();
+
+        while cpu.clock() {
+            screen.clock(&cpu);
+        }
+
+        screen.res
+    }
+}
+
+aoc_utils::example_tests!(Day10);
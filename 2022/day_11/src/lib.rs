@@ -0,0 +1,233 @@
+use aoc_utils::{
+    parser::{
+        blocks, delimited, finish, int, line, literal, map, or, pair, preceded, separated, try_map,
+        Parsed,
+    },
+    AocError, Input, Solution,
+};
+
+pub struct Day11;
+
+type ItemType = u64;
+
+#[derive(Debug, Clone, Copy)]
+enum OperationInput {
+    Old,
+    Value(ItemType),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OperationOp {
+    Sum,
+    Mul,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operation {
+    left: OperationInput,
+    right: OperationInput,
+    op: OperationOp,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<ItemType>,
+    operation: Operation,
+    divisible_by: u64,
+    true_target: usize,
+    false_target: usize,
+    inspection_count: usize,
+}
+
+#[derive(Debug, Clone)]
+struct MonkeyThrow {
+    item: ItemType,
+    target: usize,
+}
+
+fn operand(input: &str) -> Parsed<'_, OperationInput> {
+    or(
+        map(literal("old"), |_| OperationInput::Old),
+        map(int(), OperationInput::Value),
+    )(input)
+}
+
+fn operation(input: &str) -> Parsed<'_, Operation> {
+    let op = or(
+        map(literal(" + "), |_| OperationOp::Sum),
+        map(literal(" * "), |_| OperationOp::Mul),
+    );
+    map(pair(operand, pair(op, operand)), |(left, (op, right))| {
+        Operation { left, right, op }
+    })(input)
+}
+
+/// Read a throw target after `prefix`, along with its text to point at it
+/// when there is no such monkey
+fn target<'a>(prefix: &'static str) -> impl Fn(&'a str) -> Parsed<'a, (usize, &'a str)> {
+    move |input: &'a str| {
+        let (_, input) = literal(prefix)(input)?;
+        let (target, rest) = int()(input)?;
+        Ok(((target, &input[..input.len() - rest.len()]), rest))
+    }
+}
+
+impl Monkey {
+    /// Read a monkey description, monkeys are listed in order so the id is ignored
+    ///
+    /// The text of both targets comes along, they can only be checked once every
+    /// monkey is known.
+    fn parse(input: &str) -> Parsed<'_, (Monkey, [&str; 2])> {
+        let (_, input) = line(delimited(literal("Monkey "), int::<usize>(), literal(":")))(input)?;
+        let (items, input) = line(preceded(
+            literal("  Starting items: "),
+            separated(int(), literal(", ")),
+        ))(input)?;
+        let (operation, input) = line(preceded(literal("  Operation: new = "), operation))(input)?;
+        let divisor = try_map(int(), "a positive integer", |x: u64| match x {
+            0 => Err("can't test divisibility by 0".to_string()),
+            x => Ok(x),
+        });
+        let (divisible_by, input) =
+            line(preceded(literal("  Test: divisible by "), divisor))(input)?;
+        let ((true_target, true_at), input) = line(target("    If true: throw to monkey "))(input)?;
+        let ((false_target, false_at), input) =
+            line(target("    If false: throw to monkey "))(input)?;
+
+        let monkey = Monkey {
+            items,
+            operation,
+            divisible_by,
+            true_target,
+            false_target,
+            inspection_count: 0,
+        };
+        Ok(((monkey, [true_at, false_at]), input))
+    }
+
+    /// Inspect the first item, `relief` is applied to the worry level before the test
+    fn inspect_and_throw(&mut self, relief: &dyn Fn(ItemType) -> ItemType) -> Option<MonkeyThrow> {
+        if self.items.is_empty() {
+            return None;
+        }
+        self.inspection_count += 1;
+        let item = self.items.remove(0);
+        let item = relief(self.apply_operation(item));
+        let test = item.is_multiple_of(self.divisible_by);
+        Some(MonkeyThrow {
+            item,
+            target: if test {
+                self.true_target
+            } else {
+                self.false_target
+            },
+        })
+    }
+
+    fn apply_operation(&self, item: ItemType) -> ItemType {
+        let left = match self.operation.left {
+            OperationInput::Old => item,
+            OperationInput::Value(x) => x,
+        };
+        let right = match self.operation.right {
+            OperationInput::Old => item,
+            OperationInput::Value(x) => x,
+        };
+        match self.operation.op {
+            OperationOp::Mul => left * right,
+            OperationOp::Sum => left + right,
+        }
+    }
+}
+
+fn round(monkeys: &mut [Monkey], relief: &dyn Fn(ItemType) -> ItemType) {
+    for monkey_id in 0..monkeys.len() {
+        while let Some(throw) = monkeys[monkey_id].inspect_and_throw(relief) {
+            monkeys[throw.target].items.push(throw.item)
+        }
+    }
+}
+
+fn gcd(first: u64, second: u64) -> u64 {
+    let (mut max, mut min) = (first, second);
+    if min < max {
+        (min, max) = (max, min);
+    }
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return min;
+        }
+
+        (max, min) = (min, res);
+    }
+}
+
+fn lcm(first: u64, second: u64) -> u64 {
+    first * second / gcd(first, second)
+}
+
+/// Play the given amount of rounds and compute the monkey business level
+fn monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: &dyn Fn(ItemType) -> ItemType,
+) -> usize {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..rounds {
+        round(&mut monkeys, relief);
+    }
+
+    let mut counts: Vec<usize> = monkeys.iter().map(|m| m.inspection_count).collect();
+    counts.sort_unstable();
+    let best = &counts[counts.len() - 2..];
+
+    best[0] * best[1]
+}
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Vec<Monkey>, AocError> {
+        let text = input.text();
+        let parsed = finish(text, blocks(Monkey::parse))?;
+
+        let count = parsed.len();
+        // Monkey business needs the two most active monkeys
+        if count < 2 {
+            return Err(AocError::at_end(text, "expected at least 2 monkeys"));
+        }
+        for (id, (monkey, targets)) in parsed.iter().enumerate() {
+            for (target, at) in [monkey.true_target, monkey.false_target]
+                .iter()
+                .zip(targets)
+            {
+                if *target >= count {
+                    let message = format!("no monkey {target}, there are {count} monkeys");
+                    return Err(AocError::at(text, at, message));
+                }
+                // The monkey would keep catching its own item
+                if *target == id {
+                    return Err(AocError::at(text, at, "a monkey can't throw to itself"));
+                }
+            }
+        }
+        Ok(parsed.into_iter().map(|(monkey, _)| monkey).collect())
+    }
+
+    fn part1(monkeys: &Vec<Monkey>) -> usize {
+        monkey_business(monkeys, 20, &|item| item / 3)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> usize {
+        let lcm = monkeys.iter().fold(1, |prev, m| lcm(prev, m.divisible_by));
+
+        monkey_business(monkeys, 10000, &|item| item % lcm)
+    }
+}
+
+aoc_utils::example_tests!(Day11);
@@ -0,0 +1,110 @@
+use aoc_utils::{
+    grid::{Cell, Coord},
+    search, AocError, Grid, Input, Solution,
+};
+
+pub struct Day12;
+
+/// Square of the height map, `S` and `E` are the lowest and highest squares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Square(u8);
+
+impl Cell for Square {
+    const EXPECTED: &'static str = "a height `a` to `z`, `S` or `E`";
+
+    fn from_char(c: char) -> Option<Square> {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(Square(c as u8))
+    }
+
+    fn to_char(&self) -> char {
+        self.0 as char
+    }
+}
+
+impl Square {
+    fn height(self) -> u8 {
+        match self.0 {
+            b'S' => 0,
+            b'E' => b'z' - b'a' + 1,
+            cell => cell - b'a',
+        }
+    }
+}
+
+pub struct Map {
+    grid: Grid<Square>,
+    start: Coord,
+    target: Coord,
+}
+
+impl Map {
+    fn load(str: &str) -> Result<Map, AocError> {
+        let grid: Grid<Square> = Grid::parse(str)?;
+        let find = |cell: char, name| {
+            grid.find(&Square(cell as u8))
+                .ok_or_else(|| AocError::at_end(str, format!("missing the {name} `{cell}`")))
+        };
+        Ok(Map {
+            start: find('S', "start")?,
+            target: find('E', "target")?,
+            grid,
+        })
+    }
+
+    fn get_height(&self, coord: Coord) -> u8 {
+        self.grid[coord].height()
+    }
+
+    fn reachable_cells(&self, from: Coord) -> Vec<Coord> {
+        let from_height = self.get_height(from);
+        self.grid
+            .neighbours4(from)
+            .filter(|target| self.get_height(*target) <= from_height + 1)
+            .collect()
+    }
+
+    /// Squares from which `to` can be reached in one step
+    fn descending_cells(&self, to: Coord) -> Vec<Coord> {
+        let to_height = self.get_height(to);
+        self.grid
+            .neighbours4(to)
+            .filter(|from| to_height <= self.get_height(*from) + 1)
+            .collect()
+    }
+}
+
+/// Fewest steps climbing from `start` to the target
+fn climb(map: &Map, start: Coord) -> Option<usize> {
+    let path = search::bfs(
+        [start],
+        |coord| map.reachable_cells(*coord),
+        |coord| *coord == map.target,
+    )?;
+    Some(path.cost)
+}
+
+impl Solution for Day12 {
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Map, AocError> {
+        Map::load(input.text())
+    }
+
+    fn part1(map: &Map) -> usize {
+        climb(map, map.start).unwrap()
+    }
+
+    fn part2(map: &Map) -> usize {
+        // Walk down from the target to the closest `a` or `S` instead of climbing from each of them
+        let path = search::bfs(
+            [map.target],
+            |coord| map.descending_cells(*coord),
+            |coord| map.get_height(*coord) == 0,
+        );
+        path.unwrap().cost
+    }
+}
+
+aoc_utils::example_tests!(Day12);
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use aoc_utils::{
+    parser::{blocks, delimited, finish, int, line, literal, map, or, pair, separated, Parsed},
+    AocError, Input, Solution,
+};
+
+pub struct Day13;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Value {
+    Value(i32),
+    List(Vec<Value>),
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Value(a), Value::Value(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            (Value::Value(a), Value::List(b)) => vec![Value::Value(*a)].cmp(b),
+            (Value::List(a), Value::Value(b)) => a.cmp(&vec![Value::Value(*b)]),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Value(x) => write!(f, "{x}"),
+            Value::List(x) => {
+                let res: Vec<String> = x.iter().map(|x| x.to_string()).collect();
+                let res = res.join(",");
+                write!(f, "[{res}]")
+            }
+        }
+    }
+}
+
+impl Value {
+    /// Read an integer or a bracketed list of packets
+    fn parse(input: &str) -> Parsed<'_, Value> {
+        let list = delimited(
+            literal("["),
+            separated(Value::parse, literal(",")),
+            literal("]"),
+        );
+        or(map(int(), Value::Value), map(list, Value::List))(input)
+    }
+
+    /// Read a whole line as a single packet
+    fn read(line: &str) -> Result<Value, AocError> {
+        finish(line, Value::parse)
+    }
+}
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<(Value, Value)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Vec<(Value, Value)>, AocError> {
+        // A pair of packets, one per line
+        let pair = pair(line(Value::parse), line(Value::parse));
+        finish(input.text(), blocks(pair))
+    }
+
+    fn part1(pairs: &Vec<(Value, Value)>) -> usize {
+        pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (val1, val2))| val1 < val2)
+            .map(|(idx, _)| idx + 1)
+            .sum()
+    }
+
+    fn part2(pairs: &Vec<(Value, Value)>) -> usize {
+        let ref1 = Value::read("[[2]]").expect("Valid divider packet");
+        let ref2 = Value::read("[[6]]").expect("Valid divider packet");
+
+        let mut packets = vec![ref1.clone(), ref2.clone()];
+
+        for (val1, val2) in pairs {
+            packets.push(val1.clone());
+            packets.push(val2.clone());
+        }
+
+        packets.sort();
+
+        let idx1 = packets.iter().position(|x| *x == ref1).unwrap() + 1;
+        let idx2 = packets.iter().position(|x| *x == ref2).unwrap() + 1;
+
+        idx1 * idx2
+    }
+}
+
+aoc_utils::example_tests!(Day13);
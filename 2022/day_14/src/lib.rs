@@ -0,0 +1,209 @@
+use std::fmt;
+
+use aoc_utils::{
+    geometry::polyline,
+    grid,
+    parser::{finish, int, lines, literal, map, pair, separated, terminated, try_map, Parsed},
+    AocError, Grid, Input, Solution,
+};
+
+pub struct Day14;
+
+pub type Coord = grid::Coord;
+
+/// Read a `x,y` point, the cave is below the sand source so both are non negative
+fn parse_coord(input: &str) -> Parsed<'_, Coord> {
+    let value = || {
+        try_map(int(), "a non negative integer", |x: i64| match x {
+            x if x < 0 => Err(format!(
+                "invalid coordinate `{x}`, the cave has no negative side"
+            )),
+            x => Ok(x),
+        })
+    };
+    let coord = pair(terminated(value(), literal(",")), value());
+    map(coord, |(x, y)| Coord::new(x, y))(input)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    SandSource,
+    Air,
+    Rock,
+    Sand,
+}
+
+impl grid::Cell for Cell {
+    const EXPECTED: &'static str = "`.`, `#`, `o` or `+`";
+
+    fn from_char(c: char) -> Option<Cell> {
+        match c {
+            '.' => Some(Cell::Air),
+            '#' => Some(Cell::Rock),
+            'o' => Some(Cell::Sand),
+            '+' => Some(Cell::SandSource),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+            Cell::SandSource => '+',
+        }
+    }
+}
+
+struct Cave {
+    grid: Grid<Cell>,
+    /// `x` of the leftmost column of the grid
+    left: i64,
+    /// `y` of the lowest rock
+    bottom: i64,
+    source: Coord,
+    /// Whether there is an infinite floor two rows below the lowest rock
+    floor: bool,
+}
+
+enum SandMovement {
+    Stop,
+    Move(Coord),
+}
+
+impl Cave {
+    fn new(paths: &[Vec<Coord>], floor: bool) -> Cave {
+        let source = Coord::new(500, 0);
+        let rocks = || paths.iter().flatten();
+        let bottom = rocks().map(|rock| rock.y).max().unwrap_or(source.y);
+        // Sand can't pile up further than the floor depth on each side of the source
+        let spread = bottom + 2;
+        let left = rocks()
+            .map(|rock| rock.x)
+            .min()
+            .unwrap_or(source.x)
+            .min(source.x - spread);
+        let right = rocks()
+            .map(|rock| rock.x)
+            .max()
+            .unwrap_or(source.x)
+            .max(source.x + spread);
+
+        let width = (right - left + 1) as usize;
+        let height = (bottom + 3) as usize;
+        let mut cave = Cave {
+            grid: Grid::new(width, height, Cell::Air),
+            left,
+            bottom,
+            source,
+            floor,
+        };
+        cave.add(cave.source, Cell::SandSource);
+
+        for points in paths {
+            for rock in polyline(points) {
+                cave.add(rock, Cell::Rock);
+            }
+        }
+        if floor {
+            for x in left..=right {
+                cave.add(Coord::new(x, bottom + 2), Cell::Rock);
+            }
+        }
+
+        cave
+    }
+
+    fn position(&self, coord: Coord) -> grid::Coord {
+        coord - Coord::new(self.left, 0)
+    }
+
+    fn add(&mut self, coord: Coord, cell: Cell) {
+        let position = self.position(coord);
+        self.grid[position] = cell;
+    }
+
+    fn get(&self, coord: Coord) -> Cell {
+        let position = self.position(coord);
+        self.grid.get(position).copied().unwrap_or(Cell::Air)
+    }
+
+    fn check_sand_movement(&self, sand: Coord) -> SandMovement {
+        let test = Coord::new(sand.x, sand.y + 1);
+        if self.get(test) == Cell::Air {
+            return SandMovement::Move(test);
+        }
+        let test = Coord::new(sand.x - 1, sand.y + 1);
+        if self.get(test) == Cell::Air {
+            return SandMovement::Move(test);
+        }
+        let test = Coord::new(sand.x + 1, sand.y + 1);
+        if self.get(test) == Cell::Air {
+            return SandMovement::Move(test);
+        }
+
+        SandMovement::Stop
+    }
+
+    /// Drop a unit of sand, returning where it comes to rest or `None`
+    /// if it falls into the abyss
+    fn drop_sand(&mut self) -> Option<Coord> {
+        let mut sand = self.source;
+        loop {
+            match self.check_sand_movement(sand) {
+                SandMovement::Stop => {
+                    self.add(sand, Cell::Sand);
+                    return Some(sand);
+                }
+                SandMovement::Move(x) => {
+                    sand = x;
+                    if !self.floor && sand.y >= self.bottom {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<Coord>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Vec<Vec<Coord>>, AocError> {
+        let path = separated(parse_coord, literal(" -> "));
+        finish(input.text(), lines(path))
+    }
+
+    fn part1(paths: &Vec<Vec<Coord>>) -> usize {
+        let mut cave = Cave::new(paths, false);
+
+        let mut cnt = 0;
+        while cave.drop_sand().is_some() {
+            cnt += 1;
+        }
+
+        cnt
+    }
+
+    fn part2(paths: &Vec<Vec<Coord>>) -> usize {
+        let mut cave = Cave::new(paths, true);
+
+        let mut cnt = 1;
+        while cave.drop_sand() != Some(cave.source) {
+            cnt += 1;
+        }
+
+        cnt
+    }
+}
+
+aoc_utils::example_tests!(Day14);
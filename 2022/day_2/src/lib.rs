@@ -0,0 +1,56 @@
+use aoc_utils::{day2, error::next_token, AocError, Input, Solution};
+
+pub struct Day2;
+
+/// Opponent move and the raw second column, whose meaning depends on the part
+type Round<'a> = (day2::RPS, &'a str);
+
+fn parse_round(line: &str) -> Result<Round<'_>, AocError> {
+    let mut plays = line.split(' ');
+    let opponent = next_token(line, &mut plays, "opponent move")?;
+    let player = next_token(line, &mut plays, "player move")?;
+
+    let opponent_move =
+        day2::read_opponent_move(opponent).map_err(|err| err.within(line, opponent))?;
+    // Both parts use the same letters for the second column
+    day2::read_player_move(player).map_err(|err| err.within(line, player))?;
+
+    Ok((opponent_move, player))
+}
+
+fn score(me: &day2::RPS, opponent: &day2::RPS) -> i32 {
+    day2::match_score(me, opponent) + day2::move_score(me)
+}
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Round<'a>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<'a>(input: &'a Input) -> Result<Vec<Round<'a>>, AocError> {
+        input.parse_lines(parse_round)
+    }
+
+    fn part1(rounds: &Vec<Round>) -> i32 {
+        rounds
+            .iter()
+            .map(|(opponent, play)| {
+                let me = day2::read_player_move(play).expect("Checked while parsing");
+                score(&me, opponent)
+            })
+            .sum()
+    }
+
+    fn part2(rounds: &Vec<Round>) -> i32 {
+        rounds
+            .iter()
+            .map(|(opponent, suggestion)| {
+                let me = day2::compute_playbook_move(opponent, suggestion)
+                    .expect("Checked while parsing");
+                score(&me, opponent)
+            })
+            .sum()
+    }
+}
+
+aoc_utils::example_tests!(Day2);
@@ -0,0 +1,64 @@
+use aoc_utils::{day3, AocError, Input, Solution};
+
+pub struct Day3;
+
+fn split_compartments(line: &str) -> (&str, &str) {
+    let length = line.len();
+    (&line[..length / 2], &line[length / 2..])
+}
+
+fn same_items(left: &str, right: &str) -> Vec<u8> {
+    let mut res: Vec<u8> = Vec::new();
+    for char in left.as_bytes() {
+        if right.as_bytes().contains(char) && !res.contains(char) {
+            res.push(*char);
+        }
+    }
+    res
+}
+
+fn compute_priority(line: &str) -> i32 {
+    let (left, right) = split_compartments(line);
+
+    let same = same_items(left, right);
+
+    same.iter().map(day3::object_priority).sum::<i32>()
+}
+
+fn find_badge(team: &[&str]) -> u8 {
+    let first = team[0].as_bytes();
+    let second = team[1].as_bytes();
+    let third = team[2].as_bytes();
+
+    for char in first {
+        if second.contains(char) && third.contains(char) {
+            return *char;
+        }
+    }
+    panic!("Each team should have a badge");
+}
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<'a>(input: &'a Input) -> Result<Vec<&'a str>, AocError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(rucksacks: &Vec<&str>) -> i32 {
+        rucksacks.iter().map(|x| compute_priority(x)).sum()
+    }
+
+    fn part2(rucksacks: &Vec<&str>) -> i32 {
+        assert_eq!(rucksacks.len() % 3, 0, "Groups are by three");
+
+        rucksacks
+            .chunks(3)
+            .map(|team| day3::object_priority(&find_badge(team)))
+            .sum()
+    }
+}
+
+aoc_utils::example_tests!(Day3);
@@ -0,0 +1,32 @@
+use aoc_utils::{
+    day4::{parse_sections, Section},
+    AocError, Input, Solution,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<(Section, Section)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Vec<(Section, Section)>, AocError> {
+        input.parse_lines(parse_sections)
+    }
+
+    fn part1(pairs: &Vec<(Section, Section)>) -> usize {
+        pairs
+            .iter()
+            .filter(|(left, right)| left.contains(right) || right.contains(left))
+            .count()
+    }
+
+    fn part2(pairs: &Vec<(Section, Section)>) -> usize {
+        pairs
+            .iter()
+            .filter(|(left, right)| left.overlap(right))
+            .count()
+    }
+}
+
+aoc_utils::example_tests!(Day4);
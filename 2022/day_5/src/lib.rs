@@ -0,0 +1,51 @@
+use aoc_utils::{day5, AocError, Input, Solution};
+
+pub struct Day5;
+
+/// Get the top crate for all the stacks
+fn top_crates(stacks: &day5::Stacks) -> String {
+    stacks.iter().filter_map(|x| x.last()).collect()
+}
+
+impl Solution for Day5 {
+    type Input<'a> = (day5::Stacks, Vec<day5::Movement>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse<'a>(input: &'a Input) -> Result<Self::Input<'a>, AocError> {
+        // The initial stacks state comes first, then the movements
+        let Some((drawing, moves)) = input.text().split_once("\n\n") else {
+            return Err(AocError::at_end(
+                input.text(),
+                "expected a blank line between the stacks and the movements",
+            ));
+        };
+
+        let stacks = day5::parse_initial_stacks(drawing)?;
+
+        let movements =
+            day5::parse_movements(moves).map_err(|err| err.within(input.text(), moves))?;
+        day5::check_movements(&stacks, moves, &movements)
+            .map_err(|err| err.within(input.text(), moves))?;
+
+        Ok((stacks, movements))
+    }
+
+    fn part1((stacks, movements): &Self::Input<'_>) -> String {
+        let mut stacks = stacks.clone();
+
+        day5::apply_movements_9000(&mut stacks, movements);
+
+        top_crates(&stacks)
+    }
+
+    fn part2((stacks, movements): &Self::Input<'_>) -> String {
+        let mut stacks = stacks.clone();
+
+        day5::apply_movements_9001(&mut stacks, movements);
+
+        top_crates(&stacks)
+    }
+}
+
+aoc_utils::example_tests!(Day5);
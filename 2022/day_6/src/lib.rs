@@ -0,0 +1,57 @@
+use aoc_utils::{AocError, Input, Solution};
+
+pub struct Day6;
+
+fn has_duplicates(s: &str) -> bool {
+    for c in s.chars() {
+        if s.matches(c).count() > 1 {
+            return true;
+        }
+    }
+    false
+}
+
+/// Find the position right after the first marker of `size` distinct characters
+fn end_of_marker(input: &str, size: usize) -> usize {
+    let mut iter = input.chars();
+
+    let mut marker: String = String::new();
+
+    let mut idx = 0;
+
+    loop {
+        let next = iter.next().expect("Marker not found");
+
+        marker.push(next);
+
+        if marker.len() > size {
+            marker.drain(0..1);
+        }
+
+        if marker.len() == size && !has_duplicates(&marker) {
+            return idx + 1;
+        }
+
+        idx += 1;
+    }
+}
+
+impl Solution for Day6 {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Input) -> Result<&'a str, AocError> {
+        Ok(input.text().trim())
+    }
+
+    fn part1(input: &&str) -> usize {
+        end_of_marker(input, 4)
+    }
+
+    fn part2(input: &&str) -> usize {
+        end_of_marker(input, 14)
+    }
+}
+
+aoc_utils::example_tests!(Day6);
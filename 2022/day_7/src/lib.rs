@@ -0,0 +1,48 @@
+use std::{cell::RefCell, rc::Rc};
+
+use aoc_utils::{day7, AocError, Input, Solution};
+
+pub struct Day7;
+
+const TOTAL_SPACE: u32 = 70000000;
+const REQUIRED: u32 = 30000000;
+
+impl Solution for Day7 {
+    type Input<'a> = Rc<RefCell<day7::FSEntry>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<'a>(input: &'a Input) -> Result<Self::Input<'a>, AocError> {
+        let root = day7::parse_fs(input.text())?;
+
+        root.borrow_mut().update_size();
+
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input<'_>) -> u32 {
+        let mut candidates: Vec<Rc<RefCell<day7::FSEntry>>> = Vec::new();
+
+        day7::find_dir_rec(root, Box::new(|sz: u32| sz < 100000), &mut candidates);
+
+        candidates.iter().map(|x| x.borrow().size).sum()
+    }
+
+    fn part2(root: &Self::Input<'_>) -> u32 {
+        let used_space = root.borrow().size;
+        let free_space = TOTAL_SPACE - used_space;
+        let to_free = REQUIRED - free_space;
+
+        let mut candidates: Vec<Rc<RefCell<day7::FSEntry>>> = Vec::new();
+
+        day7::find_dir_rec(root, Box::new(move |sz| sz > to_free), &mut candidates);
+
+        candidates
+            .iter()
+            .map(|x| x.borrow().size)
+            .min()
+            .expect("A directory big enough should exist")
+    }
+}
+
+aoc_utils::example_tests!(Day7);
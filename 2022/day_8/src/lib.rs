@@ -0,0 +1,30 @@
+use aoc_utils::{day8, AocError, Input, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = day8::Forest;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> Result<day8::Forest, AocError> {
+        day8::parse(input.text())
+    }
+
+    fn part1(trees: &day8::Forest) -> usize {
+        trees
+            .coords()
+            .filter(|coord| day8::is_visible(trees, *coord))
+            .count()
+    }
+
+    fn part2(trees: &day8::Forest) -> u32 {
+        trees
+            .coords()
+            .map(|coord| day8::scenic_score(trees, coord))
+            .max()
+            .unwrap()
+    }
+}
+
+aoc_utils::example_tests!(Day8);
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use aoc_utils::{
+    parser::{finish, lines},
+    AocError, Direction, FromInput, Input, Point2, Solution,
+};
+
+pub struct Day9;
+
+type Knot = Point2<i64>;
+
+/// Follow the knot `other` moving one step, if it got too far
+fn catch_up(knot: Knot, other: Knot) -> Knot {
+    if knot.chebyshev(other) > 1 {
+        knot.step_towards(other)
+    } else {
+        knot
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rope<const N: usize> {
+    knots: [Knot; N],
+}
+
+impl<const N: usize> Rope<N> {
+    fn new() -> Rope<N> {
+        Rope {
+            knots: [Knot::default(); N],
+        }
+    }
+
+    fn move_head(self, direction: Direction) -> Rope<N> {
+        let mut new_rope = Rope::new();
+        new_rope.knots[0] = self.knots[0] + direction.step();
+        for i in 1..self.knots.len() {
+            let other = new_rope.knots[i - 1];
+            new_rope.knots[i] = catch_up(self.knots[i], other)
+        }
+
+        new_rope
+    }
+
+    fn tail(&self) -> Knot {
+        self.knots[N - 1]
+    }
+}
+
+/// Move of the head of the rope, like `R 4`
+#[derive(Debug, Clone, Copy, FromInput)]
+#[pattern("{direction} {steps}")]
+pub struct Motion {
+    direction: Direction,
+    steps: usize,
+}
+
+/// Count the cells visited by the tail of a rope of `N` knots
+fn visited_by_tail<const N: usize>(motions: &[Motion]) -> usize {
+    let mut rope = Rope::<N>::new();
+
+    let mut set = HashSet::new();
+
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope = rope.move_head(motion.direction);
+
+            set.insert(rope.tail());
+        }
+    }
+
+    set.len()
+}
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Motion>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> Result<Vec<Motion>, AocError> {
+        finish(input.text(), lines(Motion::parser))
+    }
+
+    fn part1(motions: &Vec<Motion>) -> usize {
+        visited_by_tail::<2>(motions)
+    }
+
+    fn part2(motions: &Vec<Motion>) -> usize {
+        visited_by_tail::<10>(motions)
+    }
+}
+
+aoc_utils::example_tests!(Day9);
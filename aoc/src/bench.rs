@@ -0,0 +1,185 @@
+use std::{
+    error::Error,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_utils::{AocError, Input, InputSource, Solution};
+
+use crate::{
+    registry,
+    report::{input_hash, Record, Report},
+};
+
+/// Durations of every run of each phase of a solution
+#[derive(Default)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+/// Run all the phases of a solution `iterations` times
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> Result<Samples, AocError> {
+    let mut samples = Samples::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = Input::new(black_box(input));
+        let parsed = black_box(S::parse(&input)?);
+        samples.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        samples.part2.push(start.elapsed());
+    }
+
+    Ok(samples)
+}
+
+/// Summary of the samples of a phase
+#[derive(Clone, Copy, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let total: Duration = sorted.iter().sum();
+        Stats {
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            mean: total / sorted.len() as u32,
+        }
+    }
+}
+
+/// Human readable duration with an appropriate unit
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{nanos:.0}ns")
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Benchmark the selected solutions and print per phase and per year summaries
+///
+/// Returns whether every day could be benchmarked.
+pub fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    iterations: usize,
+    json: bool,
+) -> Result<bool, Box<dyn Error>> {
+    if iterations == 0 {
+        return Err("At least one iteration is needed".into());
+    }
+    let entries = registry::select(year, day);
+    if entries.is_empty() {
+        return Err(format!("No solution found for {year:?} {day:?}").into());
+    }
+
+    let mut report = Report::new("bench", json);
+    let mut summary = Vec::new();
+    let mut success = true;
+
+    for entry in entries {
+        let input = InputSource::Default.read(entry.year, entry.day);
+        let hash = input.as_ref().ok().map(|input| input_hash(input));
+        let samples = input
+            .map_err(|err| err.to_string())
+            .and_then(|input| (entry.bench)(&input, iterations).map_err(|err| err.to_string()));
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(err) => {
+                // Skip the day, the others can still be timed
+                if report.human() {
+                    eprintln!("Error: {} day {}: {err}\n", entry.year, entry.day);
+                }
+                report.push(Record {
+                    year: entry.year,
+                    day: entry.day,
+                    input_hash: hash,
+                    error: Some(err),
+                    ..Record::default()
+                });
+                success = false;
+                continue;
+            }
+        };
+
+        let phases = [
+            ("parse", Stats::new(&samples.parse)),
+            ("part1", Stats::new(&samples.part1)),
+            ("part2", Stats::new(&samples.part2)),
+        ];
+        if report.human() {
+            println!("== {} day {} ({iterations} runs) ==", entry.year, entry.day);
+            println!("{:<8}{:>12}{:>12}{:>12}", "phase", "min", "median", "mean");
+            for (name, stats) in &phases {
+                println!(
+                    "{:<8}{:>12}{:>12}{:>12}",
+                    name,
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.mean)
+                );
+            }
+            println!();
+        }
+
+        summary.push((entry.year, entry.day, phases.map(|(_, stats)| stats.median)));
+        report.push(Record {
+            year: entry.year,
+            day: entry.day,
+            phases: phases.to_vec(),
+            input_hash: hash,
+            ..Record::default()
+        });
+    }
+
+    report.finish();
+    if !report.human() {
+        return Ok(success);
+    }
+
+    println!("== Summary (median) ==");
+    println!(
+        "{:<6}{:>4}{:>12}{:>12}{:>12}{:>12}",
+        "year", "day", "parse", "part1", "part2", "total"
+    );
+    let mut grand_total = Duration::ZERO;
+    for (year, day, medians) in summary {
+        let total: Duration = medians.iter().sum();
+        grand_total += total;
+        println!(
+            "{:<6}{:>4}{:>12}{:>12}{:>12}{:>12}",
+            year,
+            day,
+            format_duration(medians[0]),
+            format_duration(medians[1]),
+            format_duration(medians[2]),
+            format_duration(total)
+        );
+    }
+    println!("{:<46}{:>12}", "total", format_duration(grand_total));
+
+    Ok(success)
+}
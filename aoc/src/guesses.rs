@@ -0,0 +1,261 @@
+use std::{error::Error, fmt, fs, io, path::PathBuf};
+
+use aoc_utils::{
+    input::day_dir,
+    toml_file::{invalid_data, read_table},
+    Answer,
+};
+
+use crate::remote::Outcome;
+
+/// An answer submitted for a part and the verdict of the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+/// Why a guess should not be submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The part is already solved with the given answer
+    Solved(Answer),
+    /// The same answer was already submitted
+    AlreadyTried(Outcome),
+    /// The answer falls outside the interval left by the previous too high / too low guesses
+    OutOfBounds {
+        low: Option<i128>,
+        high: Option<i128>,
+    },
+}
+
+impl Error for Rejection {}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Solved(answer) => write!(f, "already solved with {answer}"),
+            Rejection::AlreadyTried(outcome) => write!(f, "already tried, it was {outcome}"),
+            Rejection::OutOfBounds { low, high } => {
+                let bound = |x: &Option<i128>| x.map(|x| x.to_string()).unwrap_or_default();
+                write!(
+                    f,
+                    "outside the known bounds ({}..{})",
+                    bound(low),
+                    bound(high)
+                )
+            }
+        }
+    }
+}
+
+/// History of the answers submitted for a day, stored in `<year>/day_<day>/guesses.toml` as
+/// ```toml
+/// [[guess]]
+/// part = 1
+/// answer = 1234
+/// outcome = "too high"
+/// ```
+#[derive(Debug, Default)]
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+/// Outcomes worth remembering, the other ones say nothing about the answer
+fn outcome_name(outcome: &Outcome) -> Option<&'static str> {
+    match outcome {
+        Outcome::Correct => Some("correct"),
+        Outcome::TooHigh => Some("too high"),
+        Outcome::TooLow => Some("too low"),
+        Outcome::Wrong => Some("wrong"),
+        Outcome::RateLimited(_) | Outcome::WrongLevel => None,
+    }
+}
+
+fn read_guess(table: &toml::Table) -> Option<Guess> {
+    let part = table.get("part")?.as_integer()?.try_into().ok()?;
+    let answer = match table.get("answer")? {
+        toml::Value::Integer(x) => Answer::from(*x),
+        toml::Value::String(x) => x.parse().unwrap(),
+        _ => return None,
+    };
+    let outcome = match table.get("outcome")?.as_str()? {
+        "correct" => Outcome::Correct,
+        "too high" => Outcome::TooHigh,
+        "too low" => Outcome::TooLow,
+        "wrong" => Outcome::Wrong,
+        _ => return None,
+    };
+
+    Some(Guess {
+        part,
+        answer,
+        outcome,
+    })
+}
+
+fn write_guess(guess: &Guess) -> toml::Table {
+    let answer = match guess.answer {
+        Answer::Int(x) if i64::try_from(x).is_ok() => toml::Value::Integer(x as i64),
+        _ => toml::Value::String(guess.answer.to_string()),
+    };
+
+    let mut table = toml::Table::new();
+    table.insert("part".into(), toml::Value::Integer(guess.part.into()));
+    table.insert("answer".into(), answer);
+    table.insert(
+        "outcome".into(),
+        toml::Value::String(outcome_name(&guess.outcome).unwrap_or_default().into()),
+    );
+    table
+}
+
+impl GuessLog {
+    /// Load the guesses of a day, a missing file means no guess yet
+    pub fn load(year: u16, day: u8) -> io::Result<GuessLog> {
+        let path = day_dir(year, day).join("guesses.toml");
+        let Some(table) = read_table(&path)? else {
+            return Ok(GuessLog {
+                path,
+                guesses: Vec::new(),
+            });
+        };
+
+        let guesses = match table.get("guess") {
+            Some(toml::Value::Array(guesses)) => guesses
+                .iter()
+                .map(|guess| {
+                    guess
+                        .as_table()
+                        .and_then(read_guess)
+                        .ok_or_else(|| invalid_data(&path, format!("invalid guess {guess}")))
+                })
+                .collect::<io::Result<_>>()?,
+            Some(other) => return Err(invalid_data(&path, format!("unexpected guess {other}"))),
+            None => Vec::new(),
+        };
+
+        Ok(GuessLog { path, guesses })
+    }
+
+    /// Remember a guess and save the log, outcomes saying nothing on the answer are ignored
+    pub fn record(&mut self, guess: Guess) -> io::Result<()> {
+        if outcome_name(&guess.outcome).is_none() {
+            return Ok(());
+        }
+        self.guesses.push(guess);
+
+        let guesses = self.guesses.iter().map(write_guess).map(toml::Value::Table);
+        let mut table = toml::Table::new();
+        table.insert("guess".into(), toml::Value::Array(guesses.collect()));
+        fs::write(&self.path, table.to_string())
+    }
+
+    fn of_part(&self, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |guess| guess.part == part)
+    }
+
+    /// Exclusive interval left by the too low and too high guesses of a part
+    pub fn bounds(&self, part: u8) -> (Option<i128>, Option<i128>) {
+        let answers = |outcome| {
+            self.of_part(part)
+                .filter(move |guess| guess.outcome == outcome)
+                .filter_map(|guess| match guess.answer {
+                    Answer::Int(x) => Some(x),
+                    _ => None,
+                })
+        };
+
+        (
+            answers(Outcome::TooLow).max(),
+            answers(Outcome::TooHigh).min(),
+        )
+    }
+
+    /// Tell whether an answer is worth submitting given the previous guesses
+    pub fn check(&self, part: u8, answer: &Answer) -> Result<(), Rejection> {
+        if let Some(guess) = self.of_part(part).find(|g| g.outcome == Outcome::Correct) {
+            return Err(Rejection::Solved(guess.answer.clone()));
+        }
+        if let Some(guess) = self.of_part(part).find(|g| g.answer == *answer) {
+            return Err(Rejection::AlreadyTried(guess.outcome.clone()));
+        }
+
+        let (low, high) = self.bounds(part);
+        if let Answer::Int(x) = answer {
+            if low.is_some_and(|low| *x <= low) || high.is_some_and(|high| *x >= high) {
+                return Err(Rejection::OutOfBounds { low, high });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(guesses: &[(u8, i64, Outcome)]) -> GuessLog {
+        let guesses = guesses
+            .iter()
+            .map(|(part, answer, outcome)| Guess {
+                part: *part,
+                answer: Answer::from(*answer),
+                outcome: outcome.clone(),
+            })
+            .collect();
+        GuessLog {
+            path: PathBuf::new(),
+            guesses,
+        }
+    }
+
+    #[test]
+    fn bounds_keep_the_tightest_guesses() {
+        let log = log(&[
+            (1, 10, Outcome::TooLow),
+            (1, 50, Outcome::TooHigh),
+            (1, 20, Outcome::TooLow),
+            (1, 40, Outcome::TooHigh),
+            (1, 30, Outcome::Wrong),
+            (2, 100, Outcome::TooLow),
+        ]);
+        assert_eq!(log.bounds(1), (Some(20), Some(40)));
+        assert_eq!(log.bounds(2), (Some(100), None));
+
+        assert_eq!(log.check(1, &Answer::from(25)), Ok(()));
+        let out = Err(Rejection::OutOfBounds {
+            low: Some(20),
+            high: Some(40),
+        });
+        assert_eq!(log.check(1, &Answer::from(15)), out);
+        assert_eq!(log.check(1, &Answer::from(45)), out);
+        assert_eq!(log.check(2, &Answer::from(101)), Ok(()));
+    }
+
+    #[test]
+    fn already_tried() {
+        let log = log(&[(1, 30, Outcome::Wrong)]);
+        assert_eq!(
+            log.check(1, &Answer::from(30)),
+            Err(Rejection::AlreadyTried(Outcome::Wrong))
+        );
+        assert_eq!(log.check(2, &Answer::from(30)), Ok(()));
+    }
+
+    #[test]
+    fn solved() {
+        let log = log(&[(1, 10, Outcome::TooLow), (1, 42, Outcome::Correct)]);
+        assert_eq!(
+            log.check(1, &Answer::from(42)),
+            Err(Rejection::Solved(Answer::from(42)))
+        );
+        assert_eq!(
+            log.check(1, &Answer::from(50)),
+            Err(Rejection::Solved(Answer::from(42)))
+        );
+    }
+}
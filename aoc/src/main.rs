@@ -0,0 +1,338 @@
+use std::{error::Error, path::PathBuf, process::ExitCode};
+
+use aoc_utils::{input::DEFAULT_EXAMPLE, Answer, Diagnostic, Input, InputSource};
+use clap::{Args, Parser, Subcommand};
+use guesses::{Guess, GuessLog, Rejection};
+use remote::{Outcome, Remote, RemoteArgs};
+use report::{input_hash, Record, Report};
+
+mod bench;
+mod guesses;
+mod registry;
+mod remote;
+mod report;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Where to read the puzzle input from, the day input file when nothing is given
+#[derive(Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Read the input from a file, `-` reads the standard input
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// Read the input from the standard input
+    #[arg(long)]
+    stdin: bool,
+    /// Use the named example `input_<NAME>.txt` of the day
+    #[arg(long, value_name = "NAME", num_args = 0..=1, default_missing_value = DEFAULT_EXAMPLE)]
+    example: Option<String>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        if let Some(path) = &self.input {
+            InputSource::Path(path.clone())
+        } else if self.stdin {
+            InputSource::Stdin
+        } else if let Some(name) = &self.example {
+            InputSource::Example(name.clone())
+        } else {
+            InputSource::Default
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a year, a single day or a single part
+    Run {
+        year: u16,
+        /// Day to run, required unless --all is given
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Part to run, both parts are run when missing
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every day of the year
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Print a JSON report instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the solutions against the answers stored in each day answers.toml
+    Verify {
+        /// Year to check, every year when missing
+        year: Option<u16>,
+        /// Day to check, every day when missing
+        day: Option<u8>,
+        /// Print a JSON report instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the solutions against the examples listed in each day examples.toml
+    Test {
+        /// Year to check, every year when missing
+        year: Option<u16>,
+        /// Day to check, every day when missing
+        day: Option<u8>,
+    },
+    /// Time the parse and solve phases of the solutions
+    Bench {
+        /// Year to benchmark, every year when missing
+        year: Option<u16>,
+        /// Day to benchmark, every day when missing
+        day: Option<u8>,
+        /// Number of runs of each phase
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+        /// Print a JSON report instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create and register the crate of a new day
+    New { year: u16, day: u8 },
+    /// Download the input of a day, an input already downloaded is kept
+    Fetch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    /// Run a part on the day input and submit its answer
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of running the solution
+        #[arg(long, value_parser = str::parse::<Answer>)]
+        answer: Option<Answer>,
+        /// Submit even when the previous guesses tell the answer is wrong
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+}
+
+fn run(
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    source: &InputSource,
+    json: bool,
+) -> Result<bool, Box<dyn Error>> {
+    let entries = registry::select(Some(year), day);
+    if entries.is_empty() {
+        return Err(format!("No solution found for {year} {day:?}").into());
+    }
+    if entries.len() > 1 && source.is_explicit() {
+        return Err("An explicit input can only be used with a single day".into());
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut report = Report::new("run", json);
+    let mut success = true;
+
+    for entry in entries {
+        let input = match source.read(entry.year, entry.day) {
+            Ok(input) => input,
+            Err(err) => {
+                if report.human() {
+                    eprintln!("Error: {} day {}: {err}", entry.year, entry.day);
+                }
+                report.push(Record {
+                    year: entry.year,
+                    day: entry.day,
+                    error: Some(err.to_string()),
+                    ..Record::default()
+                });
+                success = false;
+                continue;
+            }
+        };
+
+        // Hashing a large input is not free, only do it for the report
+        let hash = (!report.human()).then(|| input_hash(&input));
+        let solved = match (entry.solve)(&input, &parts) {
+            Ok(solved) => solved,
+            Err(err) => {
+                if report.human() {
+                    let origin = source.origin(entry.year, entry.day);
+                    // Error positions are relative to the normalised text
+                    let text = Input::new(&input);
+                    eprintln!("{}", Diagnostic::new(&err, text.text(), &origin));
+                }
+                report.push(Record {
+                    year: entry.year,
+                    day: entry.day,
+                    input_hash: hash,
+                    error: Some(err.to_string()),
+                    ..Record::default()
+                });
+                success = false;
+                continue;
+            }
+        };
+        // Only the day input has been submitted
+        let guesses = match source {
+            InputSource::Default => Some(GuessLog::load(entry.year, entry.day)?),
+            _ => None,
+        };
+
+        for (part, (answer, elapsed)) in parts.iter().zip(solved.parts) {
+            if report.human() {
+                println!("== {} day {} part {} ==", entry.year, entry.day, part);
+                println!("{answer}");
+
+                match guesses
+                    .as_ref()
+                    .map(|guesses| guesses.check(*part, &answer))
+                {
+                    Some(Err(Rejection::Solved(solved))) if solved == answer => (),
+                    Some(Err(rejection)) => println!("Warning: answer {rejection}"),
+                    _ => (),
+                }
+            }
+
+            report.push(Record {
+                year: entry.year,
+                day: entry.day,
+                part: Some(*part),
+                answer: Some(answer),
+                parse: Some(solved.parse),
+                elapsed: Some(elapsed),
+                input_hash: hash.clone(),
+                ..Record::default()
+            });
+        }
+    }
+
+    report.finish();
+    Ok(success)
+}
+
+fn submit(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    force: bool,
+    remote: RemoteArgs,
+) -> Result<bool, Box<dyn Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let [entry] = registry::select(Some(year), Some(day))[..] else {
+                return Err(format!("No solution found for {year} {day}").into());
+            };
+            let source = InputSource::Default;
+            let input = source.read(year, day)?;
+            match (entry.solve)(&input, &[part]) {
+                Ok(solved) => solved.answers().remove(0),
+                Err(err) => {
+                    let origin = source.origin(year, day);
+                    let text = Input::new(&input);
+                    return Err(Diagnostic::new(&err, text.text(), &origin)
+                        .to_string()
+                        .into());
+                }
+            }
+        }
+    };
+    if let Answer::Grid(_) = answer {
+        return Err(format!("Read the picture and submit it with --answer\n{answer}").into());
+    }
+
+    let mut guesses = GuessLog::load(year, day)?;
+    match guesses.check(part, &answer) {
+        Ok(()) => (),
+        Err(rejection) if force => println!("Warning: {answer} is {rejection}"),
+        Err(rejection) => {
+            return Err(format!(
+                "Not submitting {answer}, {rejection}, use --force to submit anyway"
+            )
+            .into())
+        }
+    }
+
+    println!("Submitting {year} day {day} part {part}: {answer}");
+    let outcome = Remote::new(remote)?.submit(year, day, part, &answer)?;
+    println!("{outcome}");
+
+    guesses.record(Guess {
+        part,
+        answer,
+        outcome: outcome.clone(),
+    })?;
+
+    Ok(outcome == Outcome::Correct)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let res = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            json,
+            ..
+        } => run(year, day, part, &input.source(), json),
+        Command::Verify { year, day, json } => verify::verify(year, day, json),
+        Command::Test { year, day } => verify::test(year, day),
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            json,
+        } => bench::bench(year, day, iterations, json),
+        Command::New { year, day } => scaffold::new_day(year, day).map(|dir| {
+            println!("Created {}, rebuild the runner to use it", dir.display());
+            true
+        }),
+        Command::Fetch { year, day, remote } => {
+            remote::fetch(remote, year, day).map(|(path, downloaded)| {
+                match downloaded {
+                    true => println!("Downloaded {}", path.display()),
+                    false => println!("Using cached {}", path.display()),
+                }
+                true
+            })
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            force,
+            remote,
+        } => submit(year, day, part, answer, force, remote),
+    };
+
+    match res {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::{Duration, Instant};
+
+use aoc_utils::{Answer, AocError, Input, Solution};
+
+use crate::bench::{self, Samples};
+
+/// A day solution reachable from the runner
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Parse the input and solve the requested parts
+    pub solve: fn(&str, &[u8]) -> Result<Solved, AocError>,
+    /// Time each phase over the given amount of iterations
+    pub bench: fn(&str, usize) -> Result<Samples, AocError>,
+}
+
+/// Answers of the requested parts along with the time spent in each phase
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<(Answer, Duration)>,
+}
+
+impl Solved {
+    pub fn answers(self) -> Vec<Answer> {
+        self.parts.into_iter().map(|(answer, _)| answer).collect()
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, AocError> {
+    let start = Instant::now();
+    let input = Input::new(input);
+    let parsed = S::parse(&input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed).into(),
+                2 => S::part2(&parsed).into(),
+                _ => panic!("Invalid part {part}"),
+            };
+            (answer, start.elapsed())
+        })
+        .collect();
+    Ok(Solved { parse, parts })
+}
+
+macro_rules! entry {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Entry {
+            year: $year,
+            day: $day,
+            solve: solve::<$solution>,
+            bench: bench::measure::<$solution>,
+        }
+    };
+}
+
+/// All the known solutions, sorted by year and day
+pub const SOLUTIONS: &[Entry] = &[
+    entry!(2022, 1, aoc_2022_day_1::Day1),
+    entry!(2022, 2, aoc_2022_day_2::Day2),
+    entry!(2022, 3, aoc_2022_day_3::Day3),
+    entry!(2022, 4, aoc_2022_day_4::Day4),
+    entry!(2022, 5, aoc_2022_day_5::Day5),
+    entry!(2022, 6, aoc_2022_day_6::Day6),
+    entry!(2022, 7, aoc_2022_day_7::Day7),
+    entry!(2022, 8, aoc_2022_day_8::Day8),
+    entry!(2022, 9, aoc_2022_day_9::Day9),
+    entry!(2022, 10, aoc_2022_day_10::Day10),
+    entry!(2022, 11, aoc_2022_day_11::Day11),
+    entry!(2022, 12, aoc_2022_day_12::Day12),
+    entry!(2022, 13, aoc_2022_day_13::Day13),
+    entry!(2022, 14, aoc_2022_day_14::Day14),
+];
+
+/// Find all the solutions matching the given filters
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Entry> {
+    SOLUTIONS
+        .iter()
+        .filter(|e| year.is_none_or(|y| e.year == y))
+        .filter(|e| day.is_none_or(|d| e.day == d))
+        .collect()
+}
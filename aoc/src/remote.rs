@@ -0,0 +1,294 @@
+use std::{error::Error, fmt, fs, path::PathBuf};
+
+use aoc_utils::{
+    input::{input_path, workspace_root},
+    Answer,
+};
+use clap::Args;
+use ureq::Agent;
+
+/// File of the workspace root holding the session token when it is not given otherwise
+const SESSION_FILE: &str = ".aoc_session";
+/// Sent with every request so the server operators know who is calling
+const USER_AGENT: &str = "github.com/pscamodio/aoc_rust";
+
+/// How to reach the puzzle server
+#[derive(Args)]
+pub struct RemoteArgs {
+    /// Base URL of the puzzle server, point it at a local server to test
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+    /// Session cookie of the logged in user, read from `.aoc_session` when missing
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+/// Client of the puzzle server
+pub struct Remote {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Remote {
+    pub fn new(args: RemoteArgs) -> Result<Remote, Box<dyn Error>> {
+        let session = match args.session {
+            Some(session) => session,
+            None => {
+                let path = workspace_root().join(SESSION_FILE);
+                fs::read_to_string(&path).map_err(|err| {
+                    format!(
+                        "No session token, use --session, AOC_SESSION or {}: {err}",
+                        path.display()
+                    )
+                })?
+            }
+        };
+
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Ok(Remote {
+            agent,
+            base_url: args.base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        })
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}{page}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Download the puzzle input of a day
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = self.url(year, day, "/input");
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("GET {url}: {status}").into());
+        }
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Submit the answer of a part
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Outcome, Box<dyn Error>> {
+        let url = self.url(year, day, "/answer");
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("POST {url}: {status}").into());
+        }
+
+        let page = response.body_mut().read_to_string()?;
+        Outcome::parse(&page).ok_or_else(|| format!("POST {url}: unrecognised response").into())
+    }
+}
+
+/// Download the input of a day unless it is already cached in `input.txt`
+///
+/// Returns the path of the input and whether it was downloaded, no session
+/// token is needed when the input is cached.
+pub fn fetch(args: RemoteArgs, year: u16, day: u8) -> Result<(PathBuf, bool), Box<dyn Error>> {
+    let path = input_path(year, day);
+    // `aoc new` creates an empty input waiting to be filled
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok((path, false));
+    }
+
+    let input = Remote::new(args)?.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+
+    Ok((path, true))
+}
+
+/// Verdict of the server on a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint on the direction
+    Wrong,
+    /// Submitted too early after the previous one, with the time left to wait when given
+    RateLimited(Option<String>),
+    /// The part is already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Read the verdict out of the page returned by the server
+    pub fn parse(page: &str) -> Option<Outcome> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Outcome::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Some(Outcome::RateLimited(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(Some(wait)) => write!(f, "rate limited, wait {wait}"),
+            Outcome::RateLimited(None) => write!(f, "rate limited"),
+            Outcome::WrongLevel => write!(f, "already solved or locked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wrap a verdict like the main article of the server response
+    fn page(verdict: &str) -> String {
+        format!(
+            "<main>\n<article><p>{verdict} <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>\n</main>"
+        )
+    }
+
+    #[test]
+    fn correct() {
+        let page = page("That's the right answer!  You are one gold star closer to collecting enough star fruit.");
+        assert_eq!(Outcome::parse(&page), Some(Outcome::Correct));
+    }
+
+    #[test]
+    fn too_high_and_too_low() {
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.");
+        assert_eq!(Outcome::parse(&high), Some(Outcome::TooHigh));
+        let low = page("That's not the right answer; your answer is too low.  Please wait one minute before trying again.");
+        assert_eq!(Outcome::parse(&low), Some(Outcome::TooLow));
+    }
+
+    #[test]
+    fn wrong() {
+        let page = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.");
+        assert_eq!(Outcome::parse(&page), Some(Outcome::Wrong));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let page = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait.");
+        assert_eq!(
+            Outcome::parse(&page),
+            Some(Outcome::RateLimited(Some("1m 2s".to_string())))
+        );
+        let page = page.replace("You have 1m 2s left to wait.", "");
+        assert_eq!(Outcome::parse(&page), Some(Outcome::RateLimited(None)));
+    }
+
+    #[test]
+    fn wrong_level() {
+        let page =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Outcome::parse(&page), Some(Outcome::WrongLevel));
+    }
+
+    #[test]
+    fn unrecognised() {
+        assert_eq!(Outcome::parse(&page("Puzzle inputs differ by user.")), None);
+    }
+
+    /// Answer a single request with `body`, returning the request head received
+    fn stand_in(body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            // The head ends with an empty `\r\n` line
+            while reader.read_line(&mut head).unwrap() > 2 {}
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            head
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn fetch_downloads_only_missing_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        std::env::set_var("AOC_ROOT", &root);
+        let cached = root.join("2022").join("day_1").join("input.txt");
+        fs::create_dir_all(cached.parent().unwrap()).unwrap();
+        fs::write(&cached, "1\n2\n").unwrap();
+        // `aoc new` leaves an empty input behind
+        let empty = root.join("2022").join("day_2").join("input.txt");
+        fs::create_dir_all(empty.parent().unwrap()).unwrap();
+        fs::write(&empty, "").unwrap();
+
+        // Nothing listens there and no session is needed for a cached input
+        let offline = RemoteArgs {
+            base_url: "http://127.0.0.1:1".to_string(),
+            session: None,
+        };
+        assert_eq!(fetch(offline, 2022, 1).unwrap(), (cached.clone(), false));
+        assert_eq!(fs::read_to_string(&cached).unwrap(), "1\n2\n");
+
+        let (base_url, server) = stand_in("3\n4\n");
+        let online = RemoteArgs {
+            base_url: format!("{base_url}/"),
+            session: Some("token\n".to_string()),
+        };
+        assert_eq!(fetch(online, 2022, 2).unwrap(), (empty.clone(), true));
+        assert_eq!(fs::read_to_string(&empty).unwrap(), "3\n4\n");
+        let head = server.join().unwrap().to_lowercase();
+        assert!(head.starts_with("get /2022/day/2/input "), "{head}");
+        assert!(head.contains("cookie: session=token\r\n"), "{head}");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
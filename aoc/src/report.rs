@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use aoc_utils::Answer;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::bench::Stats;
+
+/// Hash identifying an input, so results of different machines can be matched
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+/// Result of a single day or part, fields which do not apply are left out of the report
+#[derive(Default)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub status: Option<&'static str>,
+    /// Time spent parsing the input of the day
+    pub parse: Option<Duration>,
+    /// Time spent solving the part, parsing excluded
+    pub elapsed: Option<Duration>,
+    /// Timings of the benchmarked phases
+    pub phases: Vec<(&'static str, Stats)>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+fn nanos(duration: Duration) -> Value {
+    json!(duration.as_nanos() as u64)
+}
+
+fn answer(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(x) => match i64::try_from(*x) {
+            Ok(x) => json!(x),
+            Err(_) => json!(x.to_string()),
+        },
+        other => json!(other.to_string()),
+    }
+}
+
+impl Record {
+    fn to_json(&self) -> Value {
+        let mut fields = Map::new();
+        fields.insert("year".into(), json!(self.year));
+        fields.insert("day".into(), json!(self.day));
+        if let Some(part) = self.part {
+            fields.insert("part".into(), json!(part));
+        }
+        if let Some(x) = &self.answer {
+            fields.insert("answer".into(), answer(x));
+        }
+        if let Some(x) = &self.expected {
+            fields.insert("expected".into(), answer(x));
+        }
+        if let Some(status) = self.status {
+            fields.insert("status".into(), json!(status));
+        }
+        if let Some(parse) = self.parse {
+            fields.insert("parse_ns".into(), nanos(parse));
+        }
+        if let Some(elapsed) = self.elapsed {
+            fields.insert("elapsed_ns".into(), nanos(elapsed));
+        }
+        if !self.phases.is_empty() {
+            let phases = self.phases.iter().map(|(name, stats)| {
+                let stats = json!({
+                    "min_ns": nanos(stats.min),
+                    "median_ns": nanos(stats.median),
+                    "mean_ns": nanos(stats.mean),
+                });
+                (name.to_string(), stats)
+            });
+            fields.insert("timings".into(), Value::Object(phases.collect()));
+        }
+        if let Some(hash) = &self.input_hash {
+            fields.insert("input_sha256".into(), json!(hash));
+        }
+        if let Some(error) = &self.error {
+            fields.insert("error".into(), json!(error));
+        }
+        Value::Object(fields)
+    }
+}
+
+/// Results of a command, printed as JSON on stdout instead of the usual text when requested
+pub struct Report {
+    command: &'static str,
+    json: bool,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(command: &'static str, json: bool) -> Report {
+        Report {
+            command,
+            json,
+            records: Vec::new(),
+        }
+    }
+
+    /// Whether the human readable output should be printed
+    pub fn human(&self) -> bool {
+        !self.json
+    }
+
+    pub fn push(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    /// Print the JSON report when requested
+    pub fn finish(&self) {
+        if !self.json {
+            return;
+        }
+        let records: Vec<_> = self.records.iter().map(Record::to_json).collect();
+        let report = json!({ "command": self.command, "results": records });
+        println!("{report:#}");
+    }
+}
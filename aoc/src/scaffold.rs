@@ -0,0 +1,249 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_utils::{
+    examples,
+    input::{day_dir, example_path, workspace_root, DEFAULT_EXAMPLE},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const EXAMPLES_TEMPLATE: &str = include_str!("../templates/examples.toml.tmpl");
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+}
+
+/// Sort key of lines mentioning `aoc_<year>_day_<day>`
+fn day_key(line: &str) -> Option<(u16, u8)> {
+    let start = line.find("aoc_")? + 4;
+    let mut tokens = line[start..].splitn(3, '_');
+    let year = tokens.next()?.parse().ok()?;
+    if tokens.next()? != "day" {
+        return None;
+    }
+    let day = tokens
+        .next()?
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    Some((year, day))
+}
+
+/// Insert `line` among the day lines following the `after` line, keeping them sorted
+fn insert_sorted(content: &str, after: &str, line: String) -> Result<String, Box<dyn Error>> {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|l| l.contains(after))
+        .ok_or_else(|| format!("Missing `{after}`"))?
+        + 1;
+    let key = day_key(&line).ok_or("Invalid entry")?;
+
+    let days: Vec<(usize, (u16, u8))> = (start..lines.len())
+        .filter_map(|idx| day_key(&lines[idx]).map(|other| (idx, other)))
+        .collect();
+    if days.iter().any(|(_, other)| *other == key) {
+        return Ok(content.to_string());
+    }
+    let idx = match days.iter().find(|(_, other)| *other > key) {
+        Some((idx, _)) => *idx,
+        None => days.last().map_or(start, |(idx, _)| idx + 1),
+    };
+
+    lines.insert(idx, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn update(
+    path: &Path,
+    edit: impl Fn(&str) -> Result<String, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    fs::write(path, edit(&content)?)?;
+    Ok(())
+}
+
+/// Add the year folder to the workspace members
+fn register_workspace(root: &Path, year: u16) -> Result<(), Box<dyn Error>> {
+    let member = format!("\"{year}/*\"");
+    update(&root.join("Cargo.toml"), |content| {
+        if content.contains(&member) {
+            return Ok(content.to_string());
+        }
+        let start = content
+            .find("members = [")
+            .ok_or("Missing workspace members")?;
+        let end = start
+            + content[start..]
+                .find(']')
+                .ok_or("Missing workspace members end")?;
+        let members = content[start..end].trim_end();
+        let separator = if members.ends_with(',') { "" } else { "," };
+        Ok(format!(
+            "{}{members}{separator}\n    {member}\n{}",
+            &content[..start],
+            &content[end..]
+        ))
+    })
+}
+
+/// Add the day crate to the runner dependencies and solutions
+fn register_runner(root: &Path, year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let runner = root.join("aoc");
+    update(&runner.join("Cargo.toml"), |content| {
+        insert_sorted(
+            content,
+            "[dependencies]",
+            format!("aoc_{year}_day_{day} = {{ path = \"../{year}/day_{day}\" }}"),
+        )
+    })?;
+    update(&runner.join("src").join("registry.rs"), |content| {
+        insert_sorted(
+            content,
+            "pub const SOLUTIONS",
+            format!("    entry!({year}, {day}, aoc_{year}_day_{day}::Day{day}),"),
+        )
+    })
+}
+
+/// Create a new day crate from the template and register it
+pub fn new_day(year: u16, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {day}").into());
+    }
+    let root = workspace_root();
+    let dir = day_dir(year, day);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    // A half made day would stop `aoc new` from trying again
+    if let Err(err) = fill_day(&root, &dir, year, day) {
+        fs::remove_dir_all(&dir)
+            .map_err(|cleanup| format!("{err}, then removing {}: {cleanup}", dir.display()))?;
+        return Err(err);
+    }
+
+    Ok(dir)
+}
+
+/// Write the template files of the day crate in `dir` and register it
+fn fill_day(root: &Path, dir: &Path, year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TEMPLATE, year, day))?;
+    fs::write(
+        dir.join("src").join("lib.rs"),
+        render(LIB_TEMPLATE, year, day),
+    )?;
+    fs::write(dir.join("input.txt"), "")?;
+    fs::write(example_path(year, day, DEFAULT_EXAMPLE), "")?;
+    fs::write(dir.join(examples::MANIFEST), EXAMPLES_TEMPLATE)?;
+
+    register_workspace(root, year)?;
+    register_runner(root, year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPENDENCIES: &str = "[package]
+name = \"aoc\"
+
+[dependencies]
+clap = \"4\"
+aoc_2022_day_2 = { path = \"../2022/day_2\" }
+aoc_2022_day_10 = { path = \"../2022/day_10\" }
+aoc_2023_day_1 = { path = \"../2023/day_1\" }
+";
+
+    fn dependency(year: u16, day: u8) -> String {
+        format!("aoc_{year}_day_{day} = {{ path = \"../{year}/day_{day}\" }}")
+    }
+
+    #[test]
+    fn day_keys() {
+        assert_eq!(day_key(&dependency(2022, 10)), Some((2022, 10)));
+        assert_eq!(
+            day_key("    entry!(2022, 7, aoc_2022_day_7::Day7),"),
+            Some((2022, 7))
+        );
+        assert_eq!(day_key("clap = \"4\""), None);
+        assert_eq!(day_key("aoc_utils = { path = \"../aoc_utils\" }"), None);
+        assert_eq!(day_key("aoc_2022_part_1"), None);
+    }
+
+    #[test]
+    fn insert_in_day_order() {
+        // Days sort as numbers, `10` comes after `9`
+        let content = insert_sorted(DEPENDENCIES, "[dependencies]", dependency(2022, 9)).unwrap();
+        let lines: Vec<_> = content.lines().skip(4).collect();
+        assert_eq!(
+            lines,
+            [
+                "clap = \"4\"",
+                &dependency(2022, 2),
+                &dependency(2022, 9),
+                &dependency(2022, 10),
+                &dependency(2023, 1),
+            ]
+        );
+
+        let first = insert_sorted(DEPENDENCIES, "[dependencies]", dependency(2022, 1)).unwrap();
+        assert_eq!(first.lines().nth(5), Some(dependency(2022, 1).as_str()));
+        let last = insert_sorted(DEPENDENCIES, "[dependencies]", dependency(2023, 2)).unwrap();
+        assert_eq!(last.lines().last(), Some(dependency(2023, 2).as_str()));
+    }
+
+    #[test]
+    fn insert_after_the_marker() {
+        let content = insert_sorted("[dependencies]\n", "[dependencies]", dependency(2022, 1));
+        assert_eq!(
+            content.unwrap(),
+            format!("[dependencies]\n{}\n", dependency(2022, 1))
+        );
+
+        // Registering a day twice changes nothing
+        let again = insert_sorted(DEPENDENCIES, "[dependencies]", dependency(2022, 10));
+        assert_eq!(again.unwrap(), DEPENDENCIES);
+
+        assert!(insert_sorted(DEPENDENCIES, "[workspace]", dependency(2022, 1)).is_err());
+        assert!(insert_sorted(DEPENDENCIES, "[dependencies]", "clap = \"5\"".into()).is_err());
+    }
+
+    #[test]
+    fn workspace_members() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let manifest = root.join("Cargo.toml");
+        let members = |content: &str| {
+            fs::write(&manifest, content).unwrap();
+            register_workspace(&root, 2023).map(|_| fs::read_to_string(&manifest).unwrap())
+        };
+
+        let expected =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/*\",\n    \"2023/*\"\n]\n";
+        // With or without a trailing comma after the last member
+        assert_eq!(
+            members("[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/*\"\n]\n").unwrap(),
+            expected
+        );
+        assert_eq!(
+            members("[workspace]\nmembers = [\n    \"aoc\",\n    \"2022/*\",\n]\n").unwrap(),
+            expected
+        );
+        // An already registered year is left alone
+        assert_eq!(members(expected).unwrap(), expected);
+        assert!(members("[workspace]\n").is_err());
+        assert!(members("[workspace]\nmembers = [\n").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
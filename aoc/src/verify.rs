@@ -0,0 +1,232 @@
+use std::error::Error;
+
+use aoc_utils::{Answer, Answers, Diagnostic, Example, Input, InputSource};
+
+use crate::{
+    registry::{self, Entry},
+    report::{input_hash, Record, Report},
+};
+
+/// Result of checking a part against its known answer
+#[derive(Clone)]
+pub enum Status {
+    Pass(Answer),
+    Fail { expected: Answer, got: Answer },
+    Missing(Answer),
+    Error(String),
+}
+
+impl Status {
+    pub fn new(expected: Option<&Answer>, got: Answer) -> Status {
+        match expected {
+            Some(expected) if *expected == got => Status::Pass(got),
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+                got,
+            },
+            None => Status::Missing(got),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Pass(_) => "pass",
+            Status::Fail { .. } => "fail",
+            Status::Missing(_) => "missing",
+            Status::Error(_) => "error",
+        }
+    }
+}
+
+/// Print an answer on its own lines when it does not fit on one
+fn show(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(_) => format!("\n{answer}"),
+        _ => answer.to_string(),
+    }
+}
+
+/// Count and report the checked parts
+pub struct Tally {
+    report: Report,
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Tally {
+    pub fn new(report: Report) -> Tally {
+        Tally {
+            report,
+            passed: 0,
+            failed: 0,
+            missing: 0,
+        }
+    }
+
+    /// Count a part, `record` tells which part it is and gets completed from the status
+    pub fn record(&mut self, label: &str, mut record: Record, status: Status) {
+        let human = self.report.human();
+        record.status = Some(status.name());
+        match status {
+            Status::Pass(got) => {
+                self.passed += 1;
+                if human {
+                    println!("{label}: ok");
+                }
+                record.answer = Some(got);
+            }
+            Status::Fail { expected, got } => {
+                self.failed += 1;
+                if human {
+                    println!("{label}: FAIL");
+                    println!("  expected: {}", show(&expected));
+                    println!("  got:      {}", show(&got));
+                }
+                record.expected = Some(expected);
+                record.answer = Some(got);
+            }
+            Status::Missing(got) => {
+                self.missing += 1;
+                if human {
+                    println!("{label}: missing, got {}", show(&got));
+                }
+                record.answer = Some(got);
+            }
+            Status::Error(err) => {
+                self.failed += 1;
+                if human {
+                    println!("{label}: ERROR {err}");
+                }
+                record.error = Some(err);
+            }
+        }
+        self.report.push(record);
+    }
+
+    /// Print the totals and tell whether nothing failed
+    pub fn finish(&self) -> bool {
+        if self.report.human() {
+            println!(
+                "{} passed, {} failed, {} missing",
+                self.passed, self.failed, self.missing
+            );
+        }
+        self.report.finish();
+        self.failed == 0
+    }
+}
+
+/// Solve both parts of a day and compare them with the stored answers
+///
+/// Returns the hash of the input along with the status of each part.
+fn check(entry: &Entry) -> Result<(String, Vec<Status>), Box<dyn Error>> {
+    let expected = Answers::load(entry.year, entry.day)?;
+    let input = InputSource::Default.read(entry.year, entry.day)?;
+
+    let answers = (entry.solve)(&input, &[1, 2])?.answers();
+
+    let statuses = [1, 2]
+        .into_iter()
+        .zip(answers)
+        .map(|(part, got)| Status::new(expected.get(part), got))
+        .collect();
+    Ok((input_hash(&input), statuses))
+}
+
+/// Run the selected solutions and compare them with the stored answers
+///
+/// Returns whether all the known answers still match.
+pub fn verify(year: Option<u16>, day: Option<u8>, json: bool) -> Result<bool, Box<dyn Error>> {
+    let entries = registry::select(year, day);
+    if entries.is_empty() {
+        return Err(format!("No solution found for {year:?} {day:?}").into());
+    }
+
+    let mut tally = Tally::new(Report::new("verify", json));
+
+    for entry in entries {
+        let (hash, statuses) = match check(entry) {
+            Ok((hash, statuses)) => (Some(hash), statuses),
+            Err(err) => (None, vec![Status::Error(err.to_string()); 2]),
+        };
+
+        for (part, status) in [1, 2].into_iter().zip(statuses) {
+            let label = format!("{} day {:>2} part {}", entry.year, entry.day, part);
+            let record = Record {
+                year: entry.year,
+                day: entry.day,
+                part: Some(part),
+                input_hash: hash.clone(),
+                ..Record::default()
+            };
+            tally.record(&label, record, status);
+        }
+    }
+
+    Ok(tally.finish())
+}
+
+/// Run the selected solutions on their examples and compare them with the expected answers
+///
+/// Returns whether all the examples pass.
+pub fn test(year: Option<u16>, day: Option<u8>) -> Result<bool, Box<dyn Error>> {
+    let entries = registry::select(year, day);
+    if entries.is_empty() {
+        return Err(format!("No solution found for {year:?} {day:?}").into());
+    }
+
+    let mut tally = Tally::new(Report::new("test", false));
+
+    for entry in entries {
+        let examples = match Example::load(entry.year, entry.day) {
+            Ok(examples) => examples,
+            Err(err) => {
+                let label = format!("{} day {:>2}", entry.year, entry.day);
+                let record = Record {
+                    year: entry.year,
+                    day: entry.day,
+                    ..Record::default()
+                };
+                tally.record(&label, record, Status::Error(err.to_string()));
+                continue;
+            }
+        };
+
+        for example in examples {
+            let parts: Vec<u8> = [1, 2]
+                .into_iter()
+                .filter(|part| example.expected.get(*part).is_some())
+                .collect();
+            let answers = match (entry.solve)(&example.input, &parts) {
+                Ok(solved) => solved.answers().into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err); parts.len()],
+            };
+
+            for (part, got) in parts.into_iter().zip(answers) {
+                let label = format!(
+                    "{} day {:>2} {} part {}",
+                    entry.year, entry.day, example.file, part
+                );
+                let record = Record {
+                    year: entry.year,
+                    day: entry.day,
+                    part: Some(part),
+                    input_hash: Some(input_hash(&example.input)),
+                    ..Record::default()
+                };
+                let status = match got {
+                    Ok(got) => Status::new(example.expected.get(part), got),
+                    Err(err) => {
+                        let text = Input::new(&example.input);
+                        let diagnostic = Diagnostic::new(&err, text.text(), &example.file);
+                        Status::Error(format!("\n{diagnostic}"))
+                    }
+                };
+                tally.record(&label, record, status);
+            }
+        }
+    }
+
+    Ok(tally.finish())
+}
@@ -0,0 +1,230 @@
+//! Derive macros for `aoc_utils`
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+/// Piece of a `#[pattern]` template
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+/// Split a template like `move {amount} from {from}` in literals and fields,
+/// `{{` and `}}` stand for literal braces
+fn split_pattern(pattern: &LitStr) -> Result<Vec<Piece>, Error> {
+    let text = pattern.value();
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(c);
+                }
+                if !closed {
+                    return Err(Error::new_spanned(pattern, "unmatched `{` in pattern"));
+                }
+                if name.is_empty() {
+                    return Err(Error::new_spanned(pattern, "empty field in pattern"));
+                }
+                if pieces
+                    .iter()
+                    .any(|piece| matches!(piece, Piece::Field(field) if *field == name))
+                {
+                    return Err(Error::new_spanned(
+                        pattern,
+                        format!("field `{name}` appears more than once in pattern"),
+                    ));
+                }
+                if matches!(pieces.last(), Some(Piece::Field(_))) && literal.is_empty() {
+                    return Err(Error::new_spanned(
+                        pattern,
+                        "fields in a pattern must be separated by some text",
+                    ));
+                }
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Field(name));
+            }
+            '}' => return Err(Error::new_spanned(pattern, "unmatched `}` in pattern")),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+fn expand(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let name = &input.ident;
+    let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident("pattern")) else {
+        return Err(Error::new(
+            Span::call_site(),
+            "missing `#[pattern(\"...\")]` attribute",
+        ));
+    };
+    let pattern: LitStr = attr.parse_args()?;
+    let pieces = split_pattern(&pattern)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            name,
+            "FromInput can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            name,
+            "FromInput needs a struct with named fields",
+        ));
+    };
+    let field_names: Vec<&Ident> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+
+    // What the whole template looks like, used in the errors about its text
+    let shown: String = pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Literal(text) => text.clone(),
+            Piece::Field(field) => format!("<{field}>"),
+        })
+        .collect();
+    let expected = format!("`{shown}`");
+
+    // The parse cursor is named so that it can't collide with a field
+    let mut steps = Vec::new();
+    let mut parsed = Vec::new();
+    for (idx, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Literal(text) => steps.push(quote! {
+                let (_, __aoc_rest) = ::aoc_utils::parser::literal(#text)(__aoc_rest)
+                    .map_err(|failure| failure.expecting(#expected))?;
+            }),
+            Piece::Field(field) => {
+                let Some(ident) = field_names.iter().find(|ident| **ident == field) else {
+                    return Err(Error::new_spanned(
+                        &pattern,
+                        format!("no field `{field}` in `{name}`"),
+                    ));
+                };
+                let until = match pieces.get(idx + 1) {
+                    Some(Piece::Literal(text)) => quote!(::std::option::Option::Some(#text)),
+                    _ => quote!(::std::option::Option::None),
+                };
+                steps.push(quote! {
+                    let (#ident, __aoc_rest) =
+                        ::aoc_utils::parser::field(#field, #until)(__aoc_rest)?;
+                });
+                parsed.push(*ident);
+            }
+        }
+    }
+    // Fields missing from the template start from their default value
+    let defaulted = field_names.iter().filter(|ident| !parsed.contains(ident));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::aoc_utils::FromInput for #name #ty_generics #where_clause {
+            fn parser(__aoc_rest: &str) -> ::aoc_utils::parser::Parsed<'_, Self> {
+                #(#steps)*
+                let value = #name {
+                    #(#parsed,)*
+                    #(#defaulted: ::std::default::Default::default(),)*
+                };
+                Ok((value, __aoc_rest))
+            }
+        }
+    })
+}
+
+/// Parse a struct from a line template
+///
+/// Every `{field}` of the template is read with the `FromStr` implementation
+/// of the field type, up to the text following it in the template or to the
+/// end of the line. Fields absent from the template are defaulted.
+/// ```text
+/// #[derive(FromInput)]
+/// #[pattern("move {amount} from {from} to {to}")]
+/// struct Movement {
+///     amount: u8,
+///     from: u8,
+///     to: u8,
+/// }
+/// ```
+#[proc_macro_derive(FromInput, attributes(pattern))]
+pub fn derive_from_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(pattern: &str) -> Result<Vec<String>, String> {
+        let pattern = LitStr::new(pattern, Span::call_site());
+        let pieces = split_pattern(&pattern).map_err(|err| err.to_string())?;
+        Ok(pieces
+            .into_iter()
+            .map(|piece| match piece {
+                Piece::Literal(text) => text,
+                Piece::Field(field) => format!("<{field}>"),
+            })
+            .collect())
+    }
+
+    #[test]
+    fn literals_and_fields() {
+        assert_eq!(
+            split("move {amount} from {from}").unwrap(),
+            ["move ", "<amount>", " from ", "<from>"]
+        );
+        assert_eq!(split("{{{a}}}").unwrap(), ["{", "<a>", "}"]);
+        assert_eq!(split("").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        assert_eq!(
+            split("move {amount").unwrap_err(),
+            "unmatched `{` in pattern"
+        );
+        assert_eq!(
+            split("move amount}").unwrap_err(),
+            "unmatched `}` in pattern"
+        );
+        assert_eq!(split("move {}").unwrap_err(), "empty field in pattern");
+        assert_eq!(
+            split("{a} x {a}").unwrap_err(),
+            "field `a` appears more than once in pattern"
+        );
+        assert_eq!(
+            split("{a}{b}").unwrap_err(),
+            "fields in a pattern must be separated by some text"
+        );
+    }
+}
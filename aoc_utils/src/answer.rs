@@ -0,0 +1,118 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// Answer of a puzzle part
+///
+/// Answers are normalised on creation so that the same answer always has the
+/// same representation, whether it comes from a solution or from a file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Numeric answer
+    Int(i128),
+    /// Single line textual answer
+    Str(String),
+    /// Multi-line picture, like the letters drawn by the day 10 CRT
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Build a multi-line picture answer, ignoring trailing spaces and empty lines
+    pub fn grid(picture: &str) -> Answer {
+        let mut lines: Vec<String> = picture.lines().map(|x| x.trim_end().to_string()).collect();
+        while lines.last().is_some_and(|x| x.is_empty()) {
+            lines.pop();
+        }
+        Answer::Grid(lines)
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        value.as_str().into()
+    }
+}
+
+/// Numbers are recognised as such, so `"42"` is the same answer as `42`
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        let value = value.trim_end();
+        if let Ok(value) = value.parse::<i128>() {
+            Answer::Int(value)
+        } else if value.contains('\n') {
+            Answer::grid(value)
+        } else {
+            Answer::Str(value.to_string())
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Read back an answer, like converting the text without its surrounding spaces
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        Ok(s.trim().into())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{x}"),
+            Answer::Str(x) => write!(f, "{x}"),
+            Answer::Grid(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_answer_from_solutions_and_files() {
+        assert_eq!(Answer::from(42u8), Answer::Int(42));
+        assert_eq!(Answer::from(String::from("42")), Answer::Int(42));
+        assert_eq!(Answer::from("-7"), Answer::from(-7i64));
+        assert_eq!("42".parse::<Answer>(), Ok(Answer::from(42usize)));
+        assert_eq!(" 42\n".parse::<Answer>(), Ok(Answer::Int(42)));
+
+        assert_eq!(Answer::from("CMZ"), Answer::Str("CMZ".into()));
+        assert_eq!(
+            "CMZ\n".parse::<Answer>(),
+            Ok(Answer::from(String::from("CMZ")))
+        );
+        assert_ne!(Answer::from("42a"), Answer::Int(42));
+    }
+
+    #[test]
+    fn pictures() {
+        let picture = Answer::from("#..#  \n#..#\n\n");
+        assert_eq!(picture, Answer::Grid(vec!["#..#".into(), "#..#".into()]));
+        assert_eq!(picture, Answer::grid("#..#\n#..#"));
+        assert_eq!(picture.to_string(), "#..#\n#..#");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-12i32).to_string(), "-12");
+        assert_eq!(
+            Answer::Int(u64::MAX as i128 + 1).to_string(),
+            "18446744073709551616"
+        );
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::day_dir,
+    toml_file::{check_keys, invalid_data, read_table},
+    Answer,
+};
+
+/// Known answers of a day, stored in `<year>/day_<day>/answers.toml` as
+/// ```toml
+/// part1 = 24000
+/// part2 = "multi-line answers use \"\"\" strings"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+fn read_answer(path: &Path, value: &toml::Value) -> io::Result<Answer> {
+    match value {
+        toml::Value::Integer(x) => Ok(Answer::from(*x)),
+        toml::Value::String(x) => Ok(x.parse().unwrap()),
+        other => Err(invalid_data(path, format!("unexpected answer {other}"))),
+    }
+}
+
+impl Answers {
+    /// Path of the answers file of a day
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        day_dir(year, day).join("answers.toml")
+    }
+
+    /// Load the known answers of a day, a missing file means no known answer
+    pub fn load(year: u16, day: u8) -> io::Result<Answers> {
+        let path = Answers::path(year, day);
+        let Some(table) = read_table(&path)? else {
+            return Ok(Answers::default());
+        };
+        check_keys(&path, &table, &["part1", "part2"])?;
+
+        Answers::from_table(&path, &table)
+    }
+
+    /// Read the `part1` and `part2` answers of a table
+    pub(crate) fn from_table(path: &Path, table: &toml::Table) -> io::Result<Answers> {
+        let answer = |key| table.get(key).map(|x| read_answer(path, x)).transpose();
+
+        Ok(Answers {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+        })
+    }
+
+    /// Known answer of a part
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::AocError;
+
 /// Possible picks for Rock Paper Scissor
 #[derive(PartialEq, Clone)]
 pub enum RPS {
@@ -7,23 +9,27 @@ pub enum RPS {
 }
 
 /// Read an opponent move from the input file
-pub fn read_opponent_move(play: &str) -> RPS {
-    return match play {
-        "A" => RPS::Rock,
-        "B" => RPS::Paper,
-        "C" => RPS::Scissor,
-        _ => panic!("Unexpected player 1 play"),
-    };
+pub fn read_opponent_move(play: &str) -> Result<RPS, AocError> {
+    match play {
+        "A" => Ok(RPS::Rock),
+        "B" => Ok(RPS::Paper),
+        "C" => Ok(RPS::Scissor),
+        _ => Err(AocError::new(format!(
+            "expected opponent move `A`, `B` or `C`, got `{play}`"
+        ))),
+    }
 }
 
 /// Read a player move from the input file
-pub fn read_player_move(play: &str) -> RPS {
-    return match play {
-        "X" => RPS::Rock,
-        "Y" => RPS::Paper,
-        "Z" => RPS::Scissor,
-        _ => panic!("Unexpected player 1 play"),
-    };
+pub fn read_player_move(play: &str) -> Result<RPS, AocError> {
+    match play {
+        "X" => Ok(RPS::Rock),
+        "Y" => Ok(RPS::Paper),
+        "Z" => Ok(RPS::Scissor),
+        _ => Err(AocError::new(format!(
+            "expected player move `X`, `Y` or `Z`, got `{play}`"
+        ))),
+    }
 }
 
 /// Compute the winner move knowing the opponent move
@@ -45,22 +51,24 @@ pub fn losing_move(opponent: &RPS) -> RPS {
 }
 
 /// Compute the best move following the playbook
-pub fn compute_playbook_move(opponent: &RPS, playbook_suggestion: &str) -> RPS {
-    return match playbook_suggestion {
-        "X" => losing_move(opponent),
-        "Y" => opponent.clone(),
-        "Z" => winner_move(opponent),
-        _ => panic!("Unexpected player 2 play"),
-    };
+pub fn compute_playbook_move(opponent: &RPS, playbook_suggestion: &str) -> Result<RPS, AocError> {
+    match playbook_suggestion {
+        "X" => Ok(losing_move(opponent)),
+        "Y" => Ok(opponent.clone()),
+        "Z" => Ok(winner_move(opponent)),
+        _ => Err(AocError::new(format!(
+            "expected playbook suggestion `X`, `Y` or `Z`, got `{playbook_suggestion}`"
+        ))),
+    }
 }
 
 /// Compute the score depending on the selected move
 pub fn move_score(rps: &RPS) -> i32 {
-    return match rps {
+    match rps {
         RPS::Rock => 1,
         RPS::Paper => 2,
         RPS::Scissor => 3,
-    };
+    }
 }
 
 /// Compute the score checking if we win or lose
@@ -1,4 +1,4 @@
-use std::str::Split;
+use crate::{error::parse_token, AocError};
 
 #[derive(Debug)]
 pub struct Section {
@@ -7,11 +7,16 @@ pub struct Section {
 }
 
 impl Section {
-    pub fn new(mut tokens: Split<&str>) -> Section {
-        Section {
-            from: tokens.next().unwrap().parse().unwrap(),
-            to: tokens.next().unwrap().parse().unwrap(),
-        }
+    /// Read a `from-to` range
+    pub fn parse(range: &str) -> Result<Section, AocError> {
+        let Some((from, to)) = range.split_once('-') else {
+            return Err(AocError::at(range, range, "missing `-` in section"));
+        };
+
+        Ok(Section {
+            from: parse_token(range, from, "section start")?,
+            to: parse_token(range, to, "section end")?,
+        })
     }
 
     pub fn contains(&self, other: &Section) -> bool {
@@ -23,10 +28,16 @@ impl Section {
     }
 }
 
-pub fn parse_sections(line: &str) -> (Section, Section) {
-    let mut tokens = line.split(",");
-    let first = tokens.next().unwrap().split("-");
-    let second = tokens.next().unwrap().split("-");
+pub fn parse_sections(line: &str) -> Result<(Section, Section), AocError> {
+    let parse = || {
+        let Some((first, second)) = line.split_once(',') else {
+            return Err(AocError::at(line, line, "missing `,` between the sections"));
+        };
+
+        let section = |range| Section::parse(range).map_err(|err| err.within(line, range));
+
+        Ok((section(first)?, section(second)?))
+    };
 
-    (Section::new(first), Section::new(second))
+    parse().map_err(|err| err.expecting("`from-to,from-to`"))
 }
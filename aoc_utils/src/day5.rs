@@ -1,3 +1,10 @@
+use std::str::FromStr;
+
+use crate::{
+    parser::{finish, lines},
+    AocError, FromInput,
+};
+
 pub type Stack = Vec<char>;
 
 pub type Stacks = [Stack; 9];
@@ -16,89 +23,115 @@ pub fn init_stacks() -> Stacks {
     ]
 }
 
-#[derive(Debug)]
+/// Index of a stack, written from 1 to 9 in the input
+#[derive(Debug, Clone, Copy)]
+pub struct StackId(u8);
+
+impl FromStr for StackId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<StackId, String> {
+        match s.parse::<u8>().map_err(|err| err.to_string())? {
+            id @ 1..=9 => Ok(StackId(id - 1)),
+            _ => Err("stacks are numbered from 1 to 9".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, FromInput)]
+#[pattern("move {amount} from {from} to {to}")]
 pub struct Movement {
     amount: u8,
-    from: u8,
-    to: u8,
+    from: StackId,
+    to: StackId,
 }
 
 impl Movement {
     pub fn new(amount: u8, from: u8, to: u8) -> Movement {
-        Movement { amount, from, to }
+        Movement {
+            amount,
+            from: StackId(from),
+            to: StackId(to),
+        }
     }
 }
 
-pub fn parse_stack_line(line: &str, stacks: &mut Stacks) {
+/// Read a line of the crates drawing, like `[Z] [M]     [P]`
+pub fn parse_stack_line(line: &str, stacks: &mut Stacks) -> Result<(), AocError> {
     if !line.contains("[") {
-        return;
+        return Ok(());
     }
-    let mut s = line.chars().peekable();
-    let mut stack_id = 0;
-    while s.peek().is_some() {
-        // Consume the first 4 chars of the string
-        let chunk: Vec<char> = s.by_ref().take(4).collect();
-
-        // Get out the crate letter
-        let char = &chunk[1];
-
+    // Crate letters sit every 4 characters, right after the opening bracket
+    for (stack_id, (idx, char)) in line.char_indices().skip(1).step_by(4).enumerate() {
         // If it's not empty push on the stack
-        if *char != ' ' {
-            stacks[stack_id].push(*char);
+        if char == ' ' {
+            continue;
         }
-
-        // Check next stack
-        stack_id += 1;
+        let Some(stack) = stacks.get_mut(stack_id) else {
+            let message = format!("expected at most {} stacks", stacks.len());
+            return Err(AocError::at(line, &line[idx..], message));
+        };
+        stack.push(char);
     }
+
+    Ok(())
 }
 
-pub fn parse_initial_stacks<'a, I>(mut input: I) -> Stacks
-where
-    I: Iterator<Item = &'a str>,
-{
+/// Read the drawing of the initial stacks, up to the blank line
+pub fn parse_initial_stacks(drawing: &str) -> Result<Stacks, AocError> {
     // Init empty stacks
     let mut stacks = init_stacks();
 
-    // Parse all initial file lines to fill the stacks
-    loop {
-        match input.next() {
-            Some("") => break,
-            Some(x) => parse_stack_line(x, &mut stacks),
-            None => break,
-        }
+    // Parse all the drawing lines to fill the stacks
+    for line in drawing.lines() {
+        parse_stack_line(line, &mut stacks).map_err(|err| err.within(drawing, line))?;
     }
 
     for stack in stacks.as_mut() {
         stack.reverse();
     }
 
-    stacks
+    Ok(stacks)
 }
 
-pub fn parse_movements<'a, I>(input: I) -> Vec<Movement>
-where
-    I: Iterator<Item = &'a str>,
-{
-    let mut movements: Vec<Movement> = Vec::new();
-
-    for line in input {
-        let mut tokens = line.split(" ");
-        movements.push(Movement::new(
-            tokens.nth(1).unwrap().parse().unwrap(),
-            tokens.nth(1).unwrap().parse::<u8>().unwrap() - 1,
-            tokens.nth(1).unwrap().parse::<u8>().unwrap() - 1,
-        ))
-    }
+pub fn parse_movements(moves: &str) -> Result<Vec<Movement>, AocError> {
+    finish(moves, lines(Movement::parser))
+}
 
-    movements
+/// Check that every movement finds enough crates on its stack, `moves` is the
+/// text the `movements` were read from, one per line
+///
+/// Both cranes move the same amount of crates, so the stack heights don't depend
+/// on the crane used.
+pub fn check_movements(
+    stacks: &Stacks,
+    moves: &str,
+    movements: &[Movement],
+) -> Result<(), AocError> {
+    let mut heights = stacks.clone().map(|stack| stack.len());
+    for (movement, line) in movements.iter().zip(moves.lines()) {
+        let (from, to) = (movement.from.0 as usize, movement.to.0 as usize);
+        let amount = movement.amount as usize;
+        if heights[from] < amount {
+            let message = format!(
+                "can't move {amount} crates from stack {}, it holds {}",
+                from + 1,
+                heights[from]
+            );
+            return Err(AocError::at(moves, line, message));
+        }
+        heights[from] -= amount;
+        heights[to] += amount;
+    }
+    Ok(())
 }
 
 pub fn apply_movement_9000(stacks: &mut Stacks, movement: &Movement) {
     for _ in 0..movement.amount {
-        let from = &mut stacks[movement.from as usize];
+        let from = &mut stacks[movement.from.0 as usize];
         let c = from.pop().unwrap();
 
-        let to = &mut stacks[movement.to as usize];
+        let to = &mut stacks[movement.to.0 as usize];
         to.push(c);
     }
 }
@@ -110,13 +143,13 @@ pub fn apply_movements_9000(stacks: &mut Stacks, movements: &Vec<Movement>) {
 }
 
 pub fn apply_movement_9001(stacks: &mut Stacks, movement: &Movement) {
-    let from = &mut stacks[movement.from as usize];
+    let from = &mut stacks[movement.from.0 as usize];
 
     let start = from.len() - movement.amount as usize;
 
-    let mut to_move: Vec<char> = from.splice(start..from.len() as usize, []).collect();
+    let mut to_move: Vec<char> = from.splice(start..from.len(), []).collect();
 
-    let to = &mut stacks[movement.to as usize];
+    let to = &mut stacks[movement.to.0 as usize];
 
     to.append(&mut to_move);
 }
@@ -1,5 +1,7 @@
 use std::{cell::RefCell, fmt, rc::Rc};
 
+use crate::{error::parse_token, AocError};
+
 #[derive(Debug)]
 pub struct FSEntry {
     pub name: String,
@@ -20,7 +22,7 @@ impl FSEntry {
             name,
             size,
             children: Vec::new(),
-            parent: parent,
+            parent,
             is_dir,
         }))
     }
@@ -62,7 +64,7 @@ impl FSEntry {
 
 fn find_dir_rec_impl(
     node: &Rc<RefCell<FSEntry>>,
-    predicate: &Box<dyn Fn(u32) -> bool>,
+    predicate: &dyn Fn(u32) -> bool,
     found: &mut Vec<Rc<RefCell<FSEntry>>>,
 ) {
     if node.borrow().is_dir && predicate(node.borrow().size) {
@@ -90,7 +92,7 @@ pub fn find_dir_rec(
 
 impl fmt::Display for FSEntry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} : {}\n", self.full_path(), self.size)?;
+        writeln!(f, "{} : {}", self.full_path(), self.size)?;
         for child in &self.children {
             child.borrow().fmt(f)?;
         }
@@ -98,25 +100,25 @@ impl fmt::Display for FSEntry {
     }
 }
 
-pub fn parse_fs(input: String) -> Rc<RefCell<FSEntry>> {
+pub fn parse_fs(input: &str) -> Result<Rc<RefCell<FSEntry>>, AocError> {
     let root = FSEntry::new(true, "/".to_string(), 0, None);
     let mut current = root.clone();
 
     for line in input.lines() {
+        let error = |message: &str| AocError::new(message).within(input, line);
+
         // Ignore root cd and all dir lines, we don't care about them
         if line.starts_with("$ cd /") || line.starts_with("dir") || line.starts_with("$ ls") {
             continue;
         }
         // cd .. go up one level
         if line.starts_with("$ cd ..") {
-            let parent = current.borrow().parent.clone().unwrap();
-            current = parent;
+            let parent = current.borrow().parent.clone();
+            current = parent.ok_or_else(|| error("cannot go above the root folder"))?;
             continue;
         }
         // bare cd create a new folder in our tree representation
-        if line.starts_with("$ cd") {
-            let folder = &line[5..];
-
+        if let Some(folder) = line.strip_prefix("$ cd ") {
             let child = FSEntry::new(true, folder.to_string(), 0, Some(current.clone()));
 
             current.borrow_mut().append(child.clone());
@@ -125,13 +127,18 @@ pub fn parse_fs(input: String) -> Rc<RefCell<FSEntry>> {
 
             continue;
         }
+        if line.starts_with('$') {
+            return Err(error("expected `$ cd <folder>` or `$ ls`"));
+        }
         // the rest are files
-        let mut tokens = line.split(" ");
-        let size: u32 = tokens.next().unwrap().parse().unwrap();
-        let name: &str = tokens.next().unwrap();
+        let Some((size, name)) = line.split_once(' ') else {
+            return Err(error("expected `<size> <name>` or `dir <name>`"));
+        };
+        let size: u32 =
+            parse_token(line, size, "file size").map_err(|err| err.within(input, line))?;
         let child = FSEntry::new(false, name.to_string(), size, Some(current.clone()));
         current.borrow_mut().append(child);
     }
 
-    root
+    Ok(root)
 }
@@ -0,0 +1,140 @@
+use std::fmt;
+
+use crate::AocError;
+
+/// Parse error shown along with the offending input line, like
+/// ```text
+/// error: invalid section end `x`: invalid digit found in string
+///  --> 2022/day_4/input.txt:2:3
+///   |
+/// 2 | 2-x,4-5
+///   |   ^ expected `from-to,from-to`
+/// ```
+pub struct Diagnostic<'a> {
+    error: &'a AocError,
+    source: &'a str,
+    origin: &'a str,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// # Arguments
+    /// * 'error' - Error found while parsing `source`
+    /// * 'source' - Whole parsed input
+    /// * 'origin' - Where the input comes from, usually its path
+    pub fn new(error: &'a AocError, source: &'a str, origin: &'a str) -> Diagnostic<'a> {
+        Diagnostic {
+            error,
+            source,
+            origin,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = self.error;
+        // Errors about a missing end of input can point right after the last line
+        let line = self
+            .source
+            .lines()
+            .nth(error.line.saturating_sub(1))
+            .unwrap_or_default();
+        let number = error.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        // Keep the tabs so the caret stays aligned with the line above
+        let padding: String = line
+            .chars()
+            .take(error.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(error.width);
+
+        writeln!(f, "error: {}", error.message)?;
+        writeln!(
+            f,
+            "{gutter}--> {}:{}:{}",
+            self.origin, error.line, error.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {line}")?;
+        write!(f, "{gutter} | {padding}{carets}")?;
+        if let Some(expected) = &error.expected {
+            write!(f, " expected {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(error: &AocError, source: &str) -> String {
+        Diagnostic::new(error, source, "input.txt").to_string()
+    }
+
+    #[test]
+    fn points_at_the_error() {
+        let source = "1-2,3-4\n2-x,4-5\n";
+        let error = AocError::at(source, &source[10..11], "invalid section end `x`")
+            .expecting("`from-to,from-to`");
+        assert_eq!(
+            render(&error, source),
+            "error: invalid section end `x`
+ --> input.txt:2:3
+  |
+2 | 2-x,4-5
+  |   ^ expected `from-to,from-to`"
+        );
+    }
+
+    #[test]
+    fn gutter_fits_the_line_number() {
+        let source = "a\nb\nc\nd\ne\nf\ng\nh\ni\nsome bad word\n";
+        let start = source.find("bad").unwrap();
+        let error = AocError::at(source, &source[start..start + 3], "unexpected word");
+        assert_eq!(
+            render(&error, source),
+            "error: unexpected word
+  --> input.txt:10:6
+   |
+10 | some bad word
+   |      ^^^"
+        );
+    }
+
+    #[test]
+    fn tabs_keep_the_carets_aligned() {
+        let source = "\tkey =\tvalue\n";
+        let error = AocError::at(source, &source[7..12], "unknown value");
+        assert_eq!(
+            render(&error, source),
+            "error: unknown value
+ --> input.txt:1:8
+  |
+1 | \tkey =\tvalue
+  | \t     \t^^^^^"
+        );
+    }
+
+    #[test]
+    fn errors_past_the_last_line() {
+        let error = AocError::at_end("1\n2\n", "expected a third line");
+        assert_eq!(
+            render(&error, "1\n2\n"),
+            // The empty line is still shown after the gutter
+            "error: expected a third line\n --> input.txt:3:1\n  |\n3 | \n  | ^"
+        );
+
+        let error = AocError::at_end("1\n2", "expected a digit").expecting("a number");
+        assert_eq!(
+            render(&error, "1\n2"),
+            "error: expected a digit
+ --> input.txt:2:2
+  |
+2 | 2
+  |  ^ expected a number"
+        );
+    }
+}
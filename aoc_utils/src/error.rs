@@ -0,0 +1,128 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Error found while parsing a puzzle input, positions are counted from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub line: usize,
+    pub column: usize,
+    /// Amount of characters the error is about, at least 1
+    pub width: usize,
+    pub message: String,
+    /// What the input should have looked like
+    pub expected: Option<String>,
+}
+
+/// Line and column of the start of `slice` inside `text`
+///
+/// Slices not borrowed from `text` are reported at its start.
+fn position(text: &str, slice: &str) -> (usize, usize) {
+    let offset = (slice.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    if offset > text.len() {
+        return (1, 1);
+    }
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl AocError {
+    /// Error at the start of the parsed text
+    pub fn new(message: impl Into<String>) -> AocError {
+        AocError {
+            line: 1,
+            column: 1,
+            width: 1,
+            message: message.into(),
+            expected: None,
+        }
+    }
+
+    /// Error about `slice`, a part of the parsed `text`
+    pub fn at(text: &str, slice: &str, message: impl Into<String>) -> AocError {
+        AocError {
+            width: slice.chars().count().max(1),
+            ..AocError::new(message).within(text, slice)
+        }
+    }
+
+    /// Describe what was expected, replacing any more specific expectation
+    pub fn expecting(self, expected: impl Into<String>) -> AocError {
+        AocError {
+            expected: Some(expected.into()),
+            ..self
+        }
+    }
+
+    /// Error right after the end of `text`, for something missing
+    pub fn at_end(text: &str, message: impl Into<String>) -> AocError {
+        AocError::at(text, &text[text.len()..], message)
+    }
+
+    /// Move an error found while parsing `slice` to its position in the `text` holding it
+    pub fn within(self, text: &str, slice: &str) -> AocError {
+        let (line, column) = position(text, slice);
+        AocError {
+            line: line + self.line - 1,
+            column: match self.line {
+                1 => column + self.column - 1,
+                _ => self.column,
+            },
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        match &self.expected {
+            Some(expected) => write!(f, ", expected {expected}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+/// Parse a `token` of `text` with its `FromStr` implementation
+/// # Arguments
+/// * 'what' - Description of the expected value used in the error message
+pub fn parse_token<T>(text: &str, token: &str, what: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse()
+        .map_err(|err| AocError::at(text, token, format!("invalid {what} `{token}`: {err}")))
+}
+
+/// Take the next token of `text`, failing at the end of `text` when there is none left
+pub fn next_token<'a>(
+    text: &'a str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    what: &str,
+) -> Result<&'a str, AocError> {
+    tokens
+        .next()
+        .ok_or_else(|| AocError::at_end(text, format!("missing {what}")))
+}
+
+/// Parse every line of `input`, errors are moved to the line they were found on
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
@@ -0,0 +1,130 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    input::day_dir,
+    toml_file::{check_keys, invalid_data, read_table},
+    Answer, Answers, Diagnostic, Input, Solution,
+};
+
+/// Name of the examples manifest found in each day folder
+pub const MANIFEST: &str = "examples.toml";
+
+/// An example input with its expected answers, listed in the day `examples.toml` as
+/// ```toml
+/// [[example]]
+/// input = "input_simple.txt"
+/// part1 = 24
+/// part2 = 93
+/// ```
+/// A part without an expected answer is not checked.
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// Input file name, relative to the day folder
+    pub file: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+impl Example {
+    /// Load the examples of a day
+    pub fn load(year: u16, day: u8) -> io::Result<Vec<Example>> {
+        Example::load_dir(&day_dir(year, day))
+    }
+
+    /// Load the examples listed in the manifest of a day folder, a missing manifest means no example
+    pub fn load_dir(dir: &Path) -> io::Result<Vec<Example>> {
+        let path = dir.join(MANIFEST);
+        let Some(table) = read_table(&path)? else {
+            return Ok(Vec::new());
+        };
+        check_keys(&path, &table, &["example"])?;
+
+        let Some(examples) = table.get("example") else {
+            return Ok(Vec::new());
+        };
+        let examples = examples
+            .as_array()
+            .ok_or_else(|| invalid_data(&path, "example must be an array of tables"))?;
+
+        examples
+            .iter()
+            .map(|example| {
+                let example = example
+                    .as_table()
+                    .ok_or_else(|| invalid_data(&path, "example must be a table"))?;
+                check_keys(&path, example, &["input", "part1", "part2"])?;
+                let file = example
+                    .get("input")
+                    .and_then(|x| x.as_str())
+                    .ok_or_else(|| invalid_data(&path, "example without input file"))?;
+                let input_path = dir.join(file);
+                let input = fs::read_to_string(&input_path)
+                    .map_err(|err| invalid_data(&input_path, err))?;
+
+                Ok(Example {
+                    file: file.to_string(),
+                    input,
+                    expected: Answers::from_table(&path, example)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Run a solution on every example of a day folder that has an expected answer for `part`
+///
+/// # Panics
+/// When the examples can't be loaded, any example fails to parse or any answer differs,
+/// listing all the differences.
+pub fn check<S: Solution>(dir: &str, part: u8) {
+    let examples = Example::load_dir(Path::new(dir)).unwrap();
+
+    let mut failures = Vec::new();
+    for example in examples {
+        let Some(expected) = example.expected.get(part) else {
+            continue;
+        };
+        let input = Input::new(&example.input);
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                failures.push(Diagnostic::new(&err, input.text(), &example.file).to_string());
+                continue;
+            }
+        };
+        let got: Answer = match part {
+            1 => S::part1(&parsed).into(),
+            _ => S::part2(&parsed).into(),
+        };
+        if got != *expected {
+            failures.push(format!(
+                "{} part {part}: expected\n{expected}\ngot\n{got}",
+                example.file
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+/// Generate the tests running a solution on the examples listed in the crate `examples.toml`
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $crate::examples::check::<$solution>(env!("CARGO_MANIFEST_DIR"), 1);
+            }
+
+            #[test]
+            fn part2() {
+                $crate::examples::check::<$solution>(env!("CARGO_MANIFEST_DIR"), 2);
+            }
+        }
+    };
+}
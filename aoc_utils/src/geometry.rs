@@ -0,0 +1,241 @@
+//! Lines, rectangles and polygons on the integer plane
+
+use std::collections::HashSet;
+
+use crate::point::Point2;
+
+/// Points of a segment, both ends included, see [`line`]
+#[derive(Debug, Clone)]
+pub struct Line {
+    next: Option<Point2<i64>>,
+    to: Point2<i64>,
+    step: Point2<i64>,
+    delta: Point2<i64>,
+    error: i64,
+}
+
+/// Points of the segment from `from` to `to` with Bresenham's algorithm,
+/// any slope works and both ends are included
+pub fn line(from: Point2<i64>, to: Point2<i64>) -> Line {
+    let delta = Point2::new((to.x - from.x).abs(), -(to.y - from.y).abs());
+    Line {
+        next: Some(from),
+        to,
+        step: (to - from).signum(),
+        delta,
+        error: delta.x + delta.y,
+    }
+}
+
+impl Iterator for Line {
+    type Item = Point2<i64>;
+
+    fn next(&mut self) -> Option<Point2<i64>> {
+        let point = self.next?;
+        if point == self.to {
+            self.next = None;
+            return Some(point);
+        }
+        let mut next = point;
+        let double = 2 * self.error;
+        if double >= self.delta.y {
+            self.error += self.delta.y;
+            next.x += self.step.x;
+        }
+        if double <= self.delta.x {
+            self.error += self.delta.x;
+            next.y += self.step.y;
+        }
+        self.next = Some(next);
+        Some(point)
+    }
+}
+
+/// Points of the segments joining `vertices` in order, each vertex only once
+pub fn polyline(vertices: &[Point2<i64>]) -> impl Iterator<Item = Point2<i64>> + '_ {
+    let first = vertices.first().copied();
+    let rest = vertices
+        .windows(2)
+        .flat_map(|segment| line(segment[0], segment[1]).skip(1));
+    first.into_iter().chain(rest)
+}
+
+/// Rectangle aligned with the axes, bounds included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2<i64>,
+    pub max: Point2<i64>,
+}
+
+impl Rect {
+    /// Rectangle with opposite corners `a` and `b`
+    pub fn new(a: Point2<i64>, b: Point2<i64>) -> Rect {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// Amount of points in the rectangle
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point2<i64>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Points in both rectangles, `None` if they don't overlap
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Twice the area enclosed by a polygon, with the shoelace formula
+///
+/// The `vertices` are in order, the last one joins back the first one.
+pub fn double_area(vertices: &[Point2<i64>]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(a, b)| a.x * b.y - b.x * a.y).sum::<i64>().abs()
+}
+
+/// Amount of integer points on the edges of a polygon
+pub fn boundary_points(vertices: &[Point2<i64>]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(a, b)| gcd(b.x - a.x, b.y - a.y)).sum()
+}
+
+/// Amount of integer points strictly inside a polygon, with Pick's theorem
+pub fn interior_points(vertices: &[Point2<i64>]) -> i64 {
+    match double_area(vertices) {
+        // Flat polygons have no inside, and Pick's theorem does not hold for them
+        0 => 0,
+        area => (area - boundary_points(vertices)) / 2 + 1,
+    }
+}
+
+/// Amount of integer points inside a polygon or on its edges
+pub fn lattice_points(vertices: &[Point2<i64>]) -> i64 {
+    match double_area(vertices) {
+        // Flat polygons go back over their own edges, count their points once
+        0 => polyline(vertices).collect::<HashSet<_>>().len() as i64,
+        _ => interior_points(vertices) + boundary_points(vertices),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point2<i64>> {
+        coords.iter().map(|(x, y)| Point2::new(*x, *y)).collect()
+    }
+
+    #[test]
+    fn lines_of_any_slope() {
+        let line = |from: (i64, i64), to: (i64, i64)| {
+            super::line(Point2::new(from.0, from.1), Point2::new(to.0, to.1)).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            line((0, 0), (3, 0)),
+            points(&[(0, 0), (1, 0), (2, 0), (3, 0)])
+        );
+        assert_eq!(line((2, 2), (2, 2)), points(&[(2, 2)]));
+        assert_eq!(
+            line((3, 3), (0, 0)),
+            points(&[(3, 3), (2, 2), (1, 1), (0, 0)])
+        );
+        // Steep, a point per row
+        assert_eq!(
+            line((0, 0), (1, 3)),
+            points(&[(0, 0), (0, 1), (1, 2), (1, 3)])
+        );
+        // Negative slope, a point per column
+        assert_eq!(
+            line((0, 0), (5, -2)),
+            points(&[(0, 0), (1, 0), (2, -1), (3, -1), (4, -2), (5, -2)])
+        );
+        assert_eq!(
+            line((-2, 5), (0, 0)),
+            points(&[(-2, 5), (-2, 4), (-1, 3), (-1, 2), (0, 1), (0, 0)])
+        );
+    }
+
+    #[test]
+    fn polylines_share_their_vertices() {
+        let path: Vec<_> = polyline(&points(&[(0, 0), (0, 2), (2, 2)])).collect();
+        assert_eq!(path, points(&[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]));
+        assert_eq!(
+            polyline(&points(&[(5, 5)])).collect::<Vec<_>>(),
+            points(&[(5, 5)])
+        );
+        assert_eq!(polyline(&[]).count(), 0);
+    }
+
+    #[test]
+    fn rectangles() {
+        let a = Rect::new(Point2::new(4, 3), Point2::new(0, 0));
+        assert_eq!((a.width(), a.height(), a.area()), (5, 4, 20));
+        assert!(a.contains(Point2::new(4, 0)) && !a.contains(Point2::new(5, 0)));
+
+        let b = Rect::new(Point2::new(3, 2), Point2::new(6, 8));
+        let both = Rect::new(Point2::new(3, 2), Point2::new(4, 3));
+        assert_eq!(a.intersection(&b), Some(both));
+        assert_eq!(b.intersection(&a), Some(both));
+
+        // Touching on a single column still overlaps
+        let c = Rect::new(Point2::new(4, 0), Point2::new(9, 0));
+        assert_eq!(a.intersection(&c).map(|r| r.area()), Some(1));
+        let d = Rect::new(Point2::new(5, 0), Point2::new(9, 9));
+        assert!(!a.intersects(&d));
+    }
+
+    #[test]
+    fn polygon_areas() {
+        let square = points(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(lattice_points(&square), 25);
+
+        // Clockwise order and a slanted edge
+        let triangle = points(&[(0, 0), (0, 3), (3, 0)]);
+        assert_eq!(double_area(&triangle), 9);
+        assert_eq!(boundary_points(&triangle), 9);
+        assert_eq!(interior_points(&triangle), 1);
+        assert_eq!(lattice_points(&triangle), 10);
+    }
+
+    #[test]
+    fn flat_polygons() {
+        assert_eq!(lattice_points(&[]), 0);
+        assert_eq!(lattice_points(&points(&[(0, 0)])), 1);
+        assert_eq!(lattice_points(&points(&[(0, 0), (4, 0)])), 5);
+        let flat = points(&[(0, 0), (2, 0), (4, 0)]);
+        assert_eq!(interior_points(&flat), 0);
+        assert_eq!(lattice_points(&flat), 5);
+        assert_eq!(lattice_points(&points(&[(0, 0), (4, 4), (2, 2)])), 5);
+    }
+}
@@ -0,0 +1,553 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    point::{BoundingBox, Point, Point2},
+    AocError,
+};
+
+/// Position of a grid cell, `x` is the column and `y` the row
+///
+/// Coordinates are signed so that stepping out of the grid can be checked.
+pub type Coord = Point2<i64>;
+
+/// Conversion between grid cells and the characters of a map
+pub trait Cell: Sized {
+    /// Description of the valid characters used in errors
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<char> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for true and `.` for false
+impl Cell for bool {
+    const EXPECTED: &'static str = "`#` or `.`";
+
+    fn from_char(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+macro_rules! digit_cell {
+    ($($t:ty),*) => {$(
+        impl Cell for $t {
+            const EXPECTED: &'static str = "a digit";
+
+            fn from_char(c: char) -> Option<$t> {
+                c.to_digit(10).map(|digit| digit as $t)
+            }
+
+            fn to_char(&self) -> char {
+                char::from_digit(*self as u32, 10).unwrap_or('?')
+            }
+        }
+    )*};
+}
+
+digit_cell!(u8, u32);
+
+/// Rectangle of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        assert!(width > 0);
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    /// Read a map with one character per cell, all the lines must have the same length
+    ///
+    /// Trailing empty lines are ignored, an empty line inside the grid is not.
+    pub fn parse(text: &str) -> Result<Grid<T>, AocError> {
+        let rows = text.trim_end_matches('\n');
+        let Some(first) = rows.lines().next() else {
+            return Err(AocError::new("expected a grid"));
+        };
+        let width = first.chars().count();
+        if width == 0 {
+            return Err(AocError::at(text, first, "empty row").expecting(T::EXPECTED));
+        }
+        let mut cells = Vec::with_capacity(text.len());
+        let mut height = 0;
+        for line in rows.lines() {
+            let mut count = 0;
+            for (idx, c) in line.char_indices() {
+                let Some(cell) = T::from_char(c) else {
+                    let message = format!("unexpected cell `{c}`");
+                    return Err(AocError::at(text, &line[idx..idx + c.len_utf8()], message)
+                        .expecting(T::EXPECTED));
+                };
+                cells.push(cell);
+                count += 1;
+            }
+            if count != width {
+                let message = format!("expected {width} cells per row, got {count}");
+                return Err(AocError::at(text, line, message));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` columns holding `cells` row by row
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(width > 0 && cells.len().is_multiple_of(width));
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Box of the coordinates inside the grid
+    pub fn bounds(&self) -> BoundingBox<2> {
+        let last = Coord::new(self.width as i64 - 1, self.height as i64 - 1);
+        BoundingBox::new(Point::default()).include(last.into())
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        (0..self.height as i64).contains(&coord.y) && (0..self.width as i64).contains(&coord.x)
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then(|| coord.y as usize * self.width + coord.x as usize)
+    }
+
+    fn coord_of(&self, idx: usize) -> Coord {
+        Coord::new((idx % self.width) as i64, (idx / self.width) as i64)
+    }
+
+    /// Cell at `coord`, `None` outside of the grid
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Cells of a row, from left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Cells of a column, from top to bottom
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.coord_of(idx), cell))
+    }
+
+    /// Every coordinate of the grid, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| Coord::new((idx % width) as i64, (idx / width) as i64))
+    }
+
+    /// Cells sharing a side with `coord`, inside the grid
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        coord.neighbours4().filter(|coord| self.contains(*coord))
+    }
+
+    /// Cells sharing a side or a corner with `coord`, inside the grid
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        coord.neighbours8().filter(|coord| self.contains(*coord))
+    }
+
+    /// Cells met going from `from` by `step` until leaving the grid, `from` excluded
+    pub fn walk(&self, from: Coord, step: Coord) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(from + step), move |coord| Some(*coord + step))
+            .map_while(|coord| self.get(coord))
+    }
+
+    /// Borrowed rectangle of `width` by `height` cells starting at `corner`
+    ///
+    /// Panics if the rectangle does not fit in the grid.
+    pub fn view(&self, corner: Coord, width: usize, height: usize) -> GridView<'_, T> {
+        let end = Coord::new(corner.x + width as i64, corner.y + height as i64);
+        assert!(
+            corner.y >= 0
+                && corner.x >= 0
+                && end.y <= self.height as i64
+                && end.x <= self.width as i64,
+            "view of {width}x{height} at {corner} does not fit in the grid"
+        );
+        GridView {
+            grid: self,
+            corner,
+            width,
+            height,
+        }
+    }
+
+    /// First cell matching `pred`, row by row
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|idx| self.coord_of(idx))
+    }
+
+    /// Every cell matching `pred`, row by row
+    pub fn positions<'a>(
+        &'a self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| pred(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    /// First cell equal to `value`, row by row
+    pub fn find(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid of `width` by `height` cells computed by `f` from their coordinate
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Coord) -> T) -> Grid<T> {
+        assert!(width > 0);
+        let cells = (0..width * height)
+            .map(|idx| Coord::new((idx % width) as i64, (idx / width) as i64))
+            .map(f)
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |coord| {
+            self[Coord::new(coord.y, coord.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn clockwise
+    pub fn rotate_90(&self) -> Grid<T> {
+        let last = self.height as i64 - 1;
+        Grid::from_fn(self.height, self.width, |coord| {
+            self[Coord::new(coord.y, last - coord.x)].clone()
+        })
+    }
+
+    /// Rotate a half turn
+    pub fn rotate_180(&self) -> Grid<T> {
+        let mut cells = self.cells.clone();
+        cells.reverse();
+        Grid { cells, ..*self }
+    }
+
+    /// Rotate a quarter turn counterclockwise
+    pub fn rotate_270(&self) -> Grid<T> {
+        let last = self.width as i64 - 1;
+        Grid::from_fn(self.height, self.width, |coord| {
+            self[Coord::new(last - coord.y, coord.x)].clone()
+        })
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let cells = self
+            .rows()
+            .flat_map(|row| row.iter().rev().cloned())
+            .collect();
+        Grid { cells, ..*self }
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let cells = self.rows().rev().flatten().cloned().collect();
+        Grid { cells, ..*self }
+    }
+
+    /// Repeat the grid `across` times to the right and `down` times downward
+    pub fn tile(&self, across: usize, down: usize) -> Grid<T> {
+        Grid::from_fn(self.width * across, self.height * down, |coord| {
+            let row = coord.y % self.height as i64;
+            let col = coord.x % self.width as i64;
+            self[Coord::new(col, row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    /// Panics outside of the grid
+    fn index(&self, coord: Coord) -> &T {
+        match self.index_of(coord) {
+            Some(idx) => &self.cells[idx],
+            None => panic!("{coord} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        match self.index_of(coord) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!("{coord} is outside of the grid"),
+        }
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rectangle borrowed from a grid, coordinates start at its top left corner
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    corner: Coord,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        (0..self.height as i64).contains(&coord.y) && (0..self.width as i64).contains(&coord.x)
+    }
+
+    /// Cell at `coord` of the view, `None` outside of it
+    pub fn get(&self, coord: Coord) -> Option<&'a T> {
+        match self.contains(coord) {
+            true => self.grid.get(self.corner + coord),
+            false => None,
+        }
+    }
+
+    /// Cells of a row of the view, from left to right
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height);
+        let left = self.corner.x as usize;
+        &self.grid.row(self.corner.y as usize + row)[left..left + self.width]
+    }
+
+    /// Cells of a column of the view, from top to bottom
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator {
+        assert!(col < self.width);
+        let top = self.corner.y as usize;
+        self.grid
+            .column(self.corner.x as usize + col)
+            .skip(top)
+            .take(self.height)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every cell with its coordinate in the view, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + '_ {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| (Coord::new(col as i64, row as i64), cell))
+        })
+    }
+
+    /// Copy the view into its own grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::from_vec(self.width, cells)
+    }
+}
+
+impl<T> Index<Coord> for GridView<'_, T> {
+    type Output = T;
+
+    /// Panics outside of the view
+    fn index(&self, coord: Coord) -> &T {
+        match self.get(coord) {
+            Some(cell) => cell,
+            None => panic!("{coord} is outside of the view"),
+        }
+    }
+}
+
+impl<T: Cell> fmt::Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parse_rejects_bad_cells() {
+        let err = Grid::<bool>::parse("#.#\n.x.\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected.as_deref(), Some(bool::EXPECTED));
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::<char>::parse("abc\nde\nfgh\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 3 cells per row, got 2");
+    }
+
+    #[test]
+    fn parse_rejects_empty_rows() {
+        assert!(Grid::<char>::parse("").is_err());
+        assert!(Grid::<char>::parse("\n").is_err());
+    }
+
+    #[test]
+    fn parse_ignores_trailing_empty_lines() {
+        let grid = grid("ab\ncd\n\n\n");
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+
+        let err = Grid::<char>::parse("ab\n\ncd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 2 cells per row, got 0");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = grid("abc\ndef\n");
+        let corner: Vec<_> = grid.neighbours4(Coord::new(0, 0)).collect();
+        assert_eq!(corner.len(), 2);
+        assert!(corner.contains(&Coord::new(1, 0)) && corner.contains(&Coord::new(0, 1)));
+        assert_eq!(grid.neighbours4(Coord::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(2, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rotations_of_a_non_square_grid() {
+        let grid = grid("abc\ndef\n");
+        assert_eq!(grid.rotate_90().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_270().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+        assert_eq!(grid.rotate_90().rotate_90(), grid.rotate_180());
+    }
+
+    #[test]
+    fn views_and_walks() {
+        let grid = grid("abcd\nefgh\nijkl\n");
+        let view = grid.view(Coord::new(1, 1), 2, 2);
+        assert_eq!(view.to_string(), "fg\njk\n");
+        assert_eq!(view[Coord::new(1, 0)], 'g');
+        assert_eq!(view.get(Coord::new(2, 0)), None);
+        assert_eq!(view.column(1).collect::<String>(), "gk");
+
+        let right: String = grid.walk(Coord::new(1, 1), Coord::new(1, 0)).collect();
+        assert_eq!(right, "gh");
+        let up_left: String = grid.walk(Coord::new(2, 2), Coord::new(-1, -1)).collect();
+        assert_eq!(up_left, "fa");
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::point::Point;
+
+/// Hexagon of a hex grid in axial coordinates
+///
+/// The third cube coordinate `s` is implied by `q + r + s = 0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// One of the six sides of a hexagon with pointy tops
+///
+/// Hex grids with flat tops use the same offsets turned by 30 degrees, so
+/// `North` there is `NorthWest` here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection {
+    /// Every direction, counterclockwise from `East`
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// Offset of a single step in this direction
+    pub fn step(self) -> Hex {
+        match self {
+            HexDirection::East => Hex::new(1, 0),
+            HexDirection::NorthEast => Hex::new(1, -1),
+            HexDirection::NorthWest => Hex::new(0, -1),
+            HexDirection::West => Hex::new(-1, 0),
+            HexDirection::SouthWest => Hex::new(-1, 1),
+            HexDirection::SouthEast => Hex::new(0, 1),
+        }
+    }
+
+    pub fn reverse(self) -> HexDirection {
+        HexDirection::ALL[(self as usize + 3) % 6]
+    }
+}
+
+/// Reads `e`, `ne`, `nw`, `w`, `sw` and `se`
+impl FromStr for HexDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<HexDirection, String> {
+        match s {
+            "e" => Ok(HexDirection::East),
+            "ne" => Ok(HexDirection::NorthEast),
+            "nw" => Ok(HexDirection::NorthWest),
+            "w" => Ok(HexDirection::West),
+            "sw" => Ok(HexDirection::SouthWest),
+            "se" => Ok(HexDirection::SouthEast),
+            _ => Err(format!(
+                "expected a direction `e`, `ne`, `nw`, `w`, `sw` or `se`, got `{s}`"
+            )),
+        }
+    }
+}
+
+impl Hex {
+    pub const fn new(q: i64, r: i64) -> Hex {
+        Hex { q, r }
+    }
+
+    /// Third cube coordinate
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Cube coordinates `q`, `r`, `s`
+    pub fn cube(self) -> Point<3> {
+        Point::new([self.q, self.r, self.s()])
+    }
+
+    /// Hexagon of cube coordinates `q`, `r`, `s`, which must add up to 0
+    pub fn from_cube(cube: Point<3>) -> Hex {
+        assert_eq!(cube[0] + cube[1] + cube[2], 0, "invalid cube coordinates");
+        Hex::new(cube[0], cube[1])
+    }
+
+    /// Neighbour towards `direction`
+    pub fn step(self, direction: HexDirection) -> Hex {
+        self + direction.step()
+    }
+
+    /// The six hexagons sharing a side
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        HexDirection::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// Amount of steps between two hexagons
+    pub fn distance(self, other: Hex) -> i64 {
+        let diff = self - other;
+        (diff.q.abs() + diff.r.abs() + diff.s().abs()) / 2
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Hex) {
+        *self = *self - other;
+    }
+}
+
+/// Scale by a factor
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i64) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.q, self.r)
+    }
+}
+
+/// Axial coordinates as a 2D point, to store hexagons in a grid
+impl From<Hex> for Point<2> {
+    fn from(hex: Hex) -> Point<2> {
+        Point::new([hex.q, hex.r])
+    }
+}
+
+impl From<Point<2>> for Hex {
+    fn from(point: Point<2>) -> Hex {
+        Hex::new(point[0], point[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let origin = Hex::default();
+        assert_eq!(origin.distance(Hex::new(3, -3)), 3);
+        assert_eq!(origin.distance(Hex::new(2, 1)), 3);
+        assert_eq!(Hex::new(-1, 4).distance(Hex::new(2, -1)), 5);
+        assert!(origin.neighbours().all(|hex| hex.distance(origin) == 1));
+        assert_eq!(origin.neighbours().count(), 6);
+    }
+
+    #[test]
+    fn directions() {
+        for direction in HexDirection::ALL {
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.step() + direction.reverse().step(),
+                Hex::default()
+            );
+        }
+        assert_eq!(HexDirection::East.reverse(), HexDirection::West);
+        assert_eq!(HexDirection::NorthEast.reverse(), HexDirection::SouthWest);
+        assert_eq!("nw".parse(), Ok(HexDirection::NorthWest));
+        assert!("n".parse::<HexDirection>().is_err());
+
+        let hex = ["se", "sw", "se", "sw", "sw"]
+            .iter()
+            .map(|step| step.parse::<HexDirection>().unwrap())
+            .fold(Hex::default(), Hex::step);
+        assert_eq!(hex, Hex::new(-3, 5));
+        assert_eq!(hex.distance(Hex::default()), 5);
+    }
+
+    #[test]
+    fn cube_coordinates() {
+        let hex = Hex::new(2, -5);
+        assert_eq!(hex.s(), 3);
+        assert_eq!(hex.cube(), Point::new([2, -5, 3]));
+        assert_eq!(Hex::from_cube(hex.cube()), hex);
+        assert_eq!(Hex::from(Point::from(hex)), hex);
+        assert_eq!(hex.to_string(), "2,-5");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid cube coordinates")]
+    fn cube_coordinates_add_up_to_zero() {
+        Hex::from_cube(Point::new([1, 1, 1]));
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    borrow::Cow,
+    env, fmt,
+    fs::File,
+    io::{self, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+    str::{self, FromStr},
+};
+
+use memmap2::Mmap;
+
+use crate::{
+    error::{parse_lines, parse_token},
+    grid::{Cell, Grid},
+    AocError,
+};
+
+/// Name of the example used when none is given
+pub const DEFAULT_EXAMPLE: &str = "simple";
+
+/// Where to read a puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The conventional `<year>/day_<day>/input.txt` of the workspace
+    #[default]
+    Default,
+    /// An explicit file
+    Path(PathBuf),
+    /// The standard input
+    Stdin,
+    /// A named example stored next to the input as `input_<name>.txt`
+    Example(String),
+}
+
+/// Root folder holding the per year puzzle folders
+///
+/// It can be overridden with the `AOC_ROOT` environment variable, otherwise the
+/// workspace this crate was built from is used, so the current directory does not matter.
+pub fn workspace_root() -> PathBuf {
+    match env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc_utils lives in the workspace")
+            .to_path_buf(),
+    }
+}
+
+/// Folder holding the inputs of a day
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("day_{day}"))
+}
+
+/// Path of the puzzle input of a day
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input.txt")
+}
+
+/// Path of a named example of a day
+pub fn example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    day_dir(year, day).join(format!("input_{name}.txt"))
+}
+
+/// Whole text of a puzzle input, files are memory mapped rather than copied
+pub enum RawInput {
+    Mapped(Mmap),
+    Owned(String),
+}
+
+impl Deref for RawInput {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            // SAFETY: the content was checked to be UTF-8 when mapping the file
+            RawInput::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+            RawInput::Owned(text) => text,
+        }
+    }
+}
+
+fn map_file(path: &Path) -> io::Result<RawInput> {
+    let with_path =
+        |err: io::Error| io::Error::new(err.kind(), format!("{}: {err}", path.display()));
+
+    let file = File::open(path).map_err(with_path)?;
+    // SAFETY: inputs are not expected to be modified while a solution runs on them
+    let map = unsafe { Mmap::map(&file) }.map_err(with_path)?;
+    str::from_utf8(&map)
+        .map_err(|err| with_path(io::Error::new(io::ErrorKind::InvalidData, err)))?;
+
+    Ok(RawInput::Mapped(map))
+}
+
+impl InputSource {
+    /// Whether the source is tied to a specific day or can be read only once
+    pub fn is_explicit(&self) -> bool {
+        matches!(self, InputSource::Path(_) | InputSource::Stdin)
+    }
+
+    /// Where the input of the given day is read from, to point at it in messages
+    pub fn origin(&self, year: u16, day: u8) -> String {
+        let path = match self {
+            InputSource::Default => input_path(year, day),
+            InputSource::Path(path) if path.as_os_str() == "-" => return "<stdin>".into(),
+            InputSource::Path(path) => path.clone(),
+            InputSource::Stdin => return "<stdin>".into(),
+            InputSource::Example(name) => example_path(year, day, name),
+        };
+        let root = workspace_root();
+        path.strip_prefix(&root)
+            .unwrap_or(&path)
+            .display()
+            .to_string()
+    }
+
+    /// Read the whole input of the given day, files are mapped in memory instead of copied
+    pub fn read(&self, year: u16, day: u8) -> io::Result<RawInput> {
+        match self {
+            InputSource::Default => map_file(&input_path(year, day)),
+            InputSource::Path(path) if path.as_os_str() == "-" => {
+                InputSource::Stdin.read(year, day)
+            }
+            InputSource::Path(path) => map_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(RawInput::Owned(input))
+            }
+            InputSource::Example(name) => map_file(&example_path(year, day, name)),
+        }
+    }
+}
+
+/// Puzzle input text with `\n` line endings, offering the usual ways to split it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+impl<'a> Input<'a> {
+    /// Wrap a raw input, only copied when its line endings need to be normalised
+    pub fn new(text: &'a str) -> Input<'a> {
+        let text = match text.contains('\r') {
+            true => Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n")),
+            false => Cow::Borrowed(text),
+        };
+        Input { text }
+    }
+
+    /// The whole normalised text, errors positions are relative to it
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// All the lines, without a trailing empty one
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// Groups of lines separated by blank lines
+    pub fn blocks(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split("\n\n")
+            .map(|block| block.trim_matches('\n'))
+            .filter(|block| !block.is_empty())
+    }
+
+    /// Parse every line, errors are moved to the line they were found on
+    pub fn parse_lines<'s, T>(
+        &'s self,
+        parse: impl FnMut(&'s str) -> Result<T, AocError>,
+    ) -> Result<Vec<T>, AocError> {
+        parse_lines(self.text(), parse)
+    }
+
+    /// Parse every block, errors are moved to the block they were found in
+    pub fn parse_blocks<'s, T>(
+        &'s self,
+        mut parse: impl FnMut(&'s str) -> Result<T, AocError>,
+    ) -> Result<Vec<T>, AocError> {
+        self.blocks()
+            .map(|block| parse(block).map_err(|err| err.within(self.text(), block)))
+            .collect()
+    }
+
+    /// Whitespace separated words
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.text.split_whitespace()
+    }
+
+    /// Map with one character per cell, all the lines must have the same length
+    pub fn grid<T: Cell>(&self) -> Result<Grid<T>, AocError> {
+        Grid::parse(self.text())
+    }
+
+    /// Every integer of the text, whatever separates them, a `-` right before digits makes them negative
+    pub fn ints<T>(&self) -> Result<Vec<T>, AocError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let text = self.text();
+        let bytes = text.as_bytes();
+        let mut ints = Vec::new();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let negative = bytes[idx] == b'-' && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[idx].is_ascii_digit() {
+                idx += 1;
+                continue;
+            }
+            let start = idx;
+            idx += 1;
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            ints.push(parse_token(text, &text[start..idx], "integer")?);
+        }
+        Ok(ints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings_are_normalised() {
+        assert_eq!(Input::new("a\r\nb\r\n").text(), "a\nb\n");
+        assert_eq!(Input::new("a\rb\r").text(), "a\nb\n");
+        assert_eq!(Input::new("a\r\n\r\nb\rc").text(), "a\n\nb\nc");
+        assert!(matches!(Input::new("a\nb").text, Cow::Borrowed(_)));
+
+        let input = Input::new("1\r\n2\r\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn blocks_skip_repeated_blank_lines() {
+        let input = Input::new("\na\nb\n\n\n\nc\n\n\n\n\nd\n\n");
+        let blocks: Vec<_> = input.blocks().collect();
+        assert_eq!(blocks, ["a\nb", "c", "d"]);
+
+        let input = Input::new("a\r\n\r\nb\r\n");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn block_errors_point_into_the_text() {
+        let input = Input::new("1\n2\n\n\n3\nx\n");
+        let err = input
+            .parse_blocks(|block| {
+                parse_lines(block, |line| parse_token::<i32>(line, line, "number"))
+            })
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+    }
+
+    #[test]
+    fn ints() {
+        let input = Input::new("x=-4, y=12..15\n7-3 x-4\n");
+        assert_eq!(input.ints::<i64>(), Ok(vec![-4, 12, 15, 7, -3, -4]));
+        assert_eq!(Input::new("a - 3 --2").ints::<i32>(), Ok(vec![3, -2]));
+        assert_eq!(Input::new("no numbers").ints::<u8>(), Ok(vec![]));
+    }
+
+    #[test]
+    fn ints_overflow() {
+        let err = Input::new("1 2\n3 300").ints::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 3, 3));
+
+        // Negative values don't fit unsigned types
+        let err = Input::new("5 -1").ints::<u32>().unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 3, 2));
+    }
+}
@@ -1,5 +1,8 @@
-use std::{fs, io};
+// Lets the derived code name `::aoc_utils` from this crate too
+extern crate self as aoc_utils;
 
+mod answer;
+mod answers;
 pub mod day1;
 pub mod day2;
 pub mod day3;
@@ -7,16 +10,28 @@ pub mod day4;
 pub mod day5;
 pub mod day7;
 pub mod day8;
+pub mod diagnostic;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod parser;
+pub mod point;
+pub mod search;
+mod solution;
+pub mod toml_file;
 
-/// Read a files and return a vector of string one for line
-/// # Arguments
-/// * 'file_path' - Path to the input file from the project root
-pub fn read_input_lines(file_path: &str) -> io::Result<Vec<String>> {
-    println!("In file {}", file_path);
-
-    // Read the file
-    let contents = fs::read_to_string(file_path)?;
-
-    // Split by lines
-    return Ok(contents.split("\n").map(|x| x.to_string()).collect());
-}
+pub use answer::Answer;
+pub use answers::Answers;
+pub use aoc_derive::FromInput;
+pub use diagnostic::Diagnostic;
+pub use error::AocError;
+pub use examples::Example;
+pub use grid::Grid;
+pub use hex::{Hex, HexDirection};
+pub use input::{Input, InputSource};
+pub use parser::FromInput;
+pub use point::{Adjacency, BoundingBox, Direction, Point, Point2};
+pub use solution::Solution;
@@ -0,0 +1,506 @@
+//! Small parser combinators for puzzle formats
+//!
+//! A parser is any `Fn(&str) -> Parsed<T>` returning the value it read and
+//! the rest of the input. Failures keep the slice of the input they happened
+//! at, so [`finish`] can report them at their line and column.
+
+use std::{fmt, str::FromStr};
+
+use crate::AocError;
+
+/// Why a parser failed and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// Rest of the input at the failure, or the offending token when `message` is set
+    pub at: &'a str,
+    /// What the input should have looked like
+    pub expected: String,
+    /// Why an otherwise well formed token was rejected
+    pub message: Option<String>,
+}
+
+/// Value read by a parser and the rest of the input
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+impl<'a> Failure<'a> {
+    /// The input at `at` does not look like `expected`
+    pub fn expected(at: &'a str, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            at,
+            expected: expected.into(),
+            message: None,
+        }
+    }
+
+    /// The `token` looks like `expected` but is not a valid value
+    pub fn invalid(
+        token: &'a str,
+        expected: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Failure<'a> {
+        Failure {
+            at: token,
+            expected: expected.into(),
+            message: Some(message.into()),
+        }
+    }
+
+    /// Describe what was expected, replacing the more specific expectation
+    pub fn expecting(self, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            expected: expected.into(),
+            ..self
+        }
+    }
+
+    /// Convert into an error positioned inside the parsed `text`
+    pub fn into_error(self, text: &str) -> AocError {
+        if let Some(message) = self.message {
+            return AocError::at(text, self.at, message).expecting(self.expected);
+        }
+        let (token, message) = match self.at.chars().next() {
+            None => (self.at, "unexpected end of input".to_string()),
+            Some('\n') => (&self.at[..0], "unexpected end of line".to_string()),
+            Some(c) => (&self.at[..c.len_utf8()], format!("unexpected `{c}`")),
+        };
+        AocError::at(text, token, message).expecting(self.expected)
+    }
+}
+
+/// Types read with a parser, usually derived from a line template with
+/// `#[derive(FromInput)]`
+pub trait FromInput: Sized {
+    /// Read a value at the start of `input`
+    fn parser(input: &str) -> Parsed<'_, Self>;
+
+    /// Read a value from the whole `text`
+    fn from_input(text: &str) -> Result<Self, AocError> {
+        finish(text, Self::parser)
+    }
+}
+
+/// Run `parser` on the whole `text`, only trailing newlines may be left over
+pub fn finish<'a, T>(
+    text: &'a str,
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+) -> Result<T, AocError> {
+    let run = |text| {
+        let (value, rest) = parser(text)?;
+        match rest.trim_start_matches('\n') {
+            "" => Ok(value),
+            rest => Err(Failure::expected(rest, "the end of the input")),
+        }
+    };
+    run(text).map_err(|failure| failure.into_error(text))
+}
+
+/// Match the exact text `literal`
+pub fn literal<'a>(literal: &'static str) -> impl Fn(&'a str) -> Parsed<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => {
+            // Point at the first character that differs
+            let common = input
+                .char_indices()
+                .zip(literal.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(input.len().min(literal.len()), |((idx, _), _)| idx);
+            Err(Failure::expected(&input[common..], format!("`{literal}`")))
+        }
+    }
+}
+
+/// Read the longest non empty run of characters matching `pred`
+pub fn take_while<'a>(
+    what: &'static str,
+    pred: impl Fn(char) -> bool,
+) -> impl Fn(&'a str) -> Parsed<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !pred(c)).unwrap_or(input.len());
+        match end {
+            0 => Err(Failure::expected(input, what)),
+            _ => Ok((&input[..end], &input[end..])),
+        }
+    }
+}
+
+/// Read a decimal integer, negative when it starts with `-`
+pub fn int<'a, T>() -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    move |input: &'a str| {
+        let digits = input.strip_prefix('-').unwrap_or(input);
+        let sign = input.len() - digits.len();
+        let end = sign
+            + digits
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(digits.len());
+        if end == sign {
+            return Err(Failure::expected(input, "an integer"));
+        }
+        let token = &input[..end];
+        match token.parse() {
+            Ok(value) => Ok((value, &input[end..])),
+            Err(err) => Err(Failure::invalid(
+                token,
+                "an integer",
+                format!("invalid integer `{token}`: {err}"),
+            )),
+        }
+    }
+}
+
+/// Read a `name` value with its `FromStr` implementation
+///
+/// The value ends where `until` starts or at the end of the line, where
+/// trailing spaces are skipped.
+pub fn field<'a, T>(
+    name: &'static str,
+    until: Option<&'static str>,
+) -> impl Fn(&'a str) -> Parsed<'a, T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    move |input: &'a str| {
+        let line = &input[..input.find('\n').unwrap_or(input.len())];
+        let end = match until {
+            Some(until) if !until.starts_with('\n') => line.find(until).unwrap_or(line.len()),
+            _ => line.len(),
+        };
+        let token = match until {
+            Some(until) if !until.starts_with('\n') => &input[..end],
+            _ => input[..end].trim_end_matches([' ', '\t']),
+        };
+        if token.is_empty() {
+            return Err(Failure::expected(input, format!("<{name}>")));
+        }
+        match token.parse() {
+            Ok(value) => Ok((value, &input[end..])),
+            Err(err) => Err(Failure::invalid(
+                token,
+                format!("<{name}>"),
+                format!("invalid {name} `{token}`: {err}"),
+            )),
+        }
+    }
+}
+
+/// Transform the value read by `parser`
+pub fn map<'a, T, U>(
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&'a str) -> Parsed<'a, U> {
+    move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Validate the value read by `parser`, errors point at the whole token read
+/// # Arguments
+/// * 'expected' - Description of the valid values used in the error
+pub fn try_map<'a, T, U>(
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+    expected: &'static str,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Fn(&'a str) -> Parsed<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+        let token = &input[..input.len() - rest.len()];
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(message) => Err(Failure::invalid(token, expected, message)),
+        }
+    }
+}
+
+/// Try `first`, then `second` from the same place
+///
+/// When both fail at the same place their expectations are merged.
+pub fn or<'a, T>(
+    first: impl Fn(&'a str) -> Parsed<'a, T>,
+    second: impl Fn(&'a str) -> Parsed<'a, T>,
+) -> impl Fn(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let first = match first(input) {
+            Ok(parsed) => return Ok(parsed),
+            Err(failure) => failure,
+        };
+        let second = match second(input) {
+            Ok(parsed) => return Ok(parsed),
+            Err(failure) => failure,
+        };
+        // Report the failure that got the furthest into the input
+        match first.at.len().cmp(&second.at.len()) {
+            std::cmp::Ordering::Less => Err(first),
+            std::cmp::Ordering::Greater => Err(second),
+            std::cmp::Ordering::Equal if first.message.is_some() => Err(first),
+            std::cmp::Ordering::Equal => Err(Failure {
+                expected: format!("{} or {}", first.expected, second.expected),
+                ..second
+            }),
+        }
+    }
+}
+
+/// Read `first` then `second`
+pub fn pair<'a, T, U>(
+    first: impl Fn(&'a str) -> Parsed<'a, T>,
+    second: impl Fn(&'a str) -> Parsed<'a, U>,
+) -> impl Fn(&'a str) -> Parsed<'a, (T, U)> {
+    move |input: &'a str| {
+        let (first, rest) = first(input)?;
+        let (second, rest) = second(rest)?;
+        Ok(((first, second), rest))
+    }
+}
+
+/// Read `prefix` then `parser`, keeping only the value of `parser`
+pub fn preceded<'a, P, T>(
+    prefix: impl Fn(&'a str) -> Parsed<'a, P>,
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+) -> impl Fn(&'a str) -> Parsed<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// Read `parser` then `suffix`, keeping only the value of `parser`
+pub fn terminated<'a, T, S>(
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+    suffix: impl Fn(&'a str) -> Parsed<'a, S>,
+) -> impl Fn(&'a str) -> Parsed<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// Read `parser` between `open` and `close`, like a bracketed group
+pub fn delimited<'a, O, T, C>(
+    open: impl Fn(&'a str) -> Parsed<'a, O>,
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+    close: impl Fn(&'a str) -> Parsed<'a, C>,
+) -> impl Fn(&'a str) -> Parsed<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// Read `item`s separated by `separator`, possibly none
+pub fn separated<'a, T, S>(
+    item: impl Fn(&'a str) -> Parsed<'a, T>,
+    separator: impl Fn(&'a str) -> Parsed<'a, S>,
+) -> impl Fn(&'a str) -> Parsed<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let (first, mut rest) = match item(input) {
+            Ok(parsed) => parsed,
+            // Only an item failing right away means an empty list
+            Err(failure) if failure.message.is_none() && failure.at.len() == input.len() => {
+                return Ok((items, input))
+            }
+            Err(failure) => return Err(failure),
+        };
+        items.push(first);
+        // Once a separator is read an item must follow
+        while let Ok((_, after)) = separator(rest) {
+            let (value, after) = item(after)?;
+            items.push(value);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Read `parser` up to the end of the line, consuming the newline
+pub fn line<'a, T>(parser: impl Fn(&'a str) -> Parsed<'a, T>) -> impl Fn(&'a str) -> Parsed<'a, T> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+        match rest.strip_prefix('\n') {
+            Some(rest) => Ok((value, rest)),
+            None if rest.is_empty() => Ok((value, rest)),
+            None => Err(Failure::expected(rest, "the end of the line")),
+        }
+    }
+}
+
+/// Read lines with `parser` up to a blank line or the end of the input
+pub fn lines<'a, T>(
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+) -> impl Fn(&'a str) -> Parsed<'a, Vec<T>> {
+    let parser = line(parser);
+    move |mut input: &'a str| {
+        let mut values = Vec::new();
+        while !input.is_empty() && !input.starts_with('\n') {
+            let (value, rest) = parser(input)?;
+            values.push(value);
+            input = rest;
+        }
+        Ok((values, input))
+    }
+}
+
+/// Read groups of lines separated by blank lines with `parser`
+///
+/// `parser` has to consume the newline ending the last line of its block.
+pub fn blocks<'a, T>(
+    parser: impl Fn(&'a str) -> Parsed<'a, T>,
+) -> impl Fn(&'a str) -> Parsed<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut values = Vec::new();
+        let mut input = input.trim_start_matches('\n');
+        while !input.is_empty() {
+            let (value, rest) = parser(input)?;
+            values.push(value);
+            if !rest.is_empty() && !rest.starts_with('\n') {
+                return Err(Failure::expected(rest, "a blank line"));
+            }
+            input = rest.trim_start_matches('\n');
+        }
+        Ok((values, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Line, column and expectation of the error parsing `text`
+    fn failure<'a, T: fmt::Debug>(
+        text: &'a str,
+        parser: impl Fn(&'a str) -> Parsed<'a, T>,
+    ) -> (usize, usize, String) {
+        let err = finish(text, parser).unwrap_err();
+        (err.line, err.column, err.expected.unwrap_or_default())
+    }
+
+    #[test]
+    fn literal_points_at_the_first_difference() {
+        assert_eq!(literal("move")("move 3"), Ok(("move", " 3")));
+        assert_eq!(failure("mvoe 3", literal("move")), (1, 2, "`move`".into()));
+        let second = pair(line(literal("ab")), literal("xyz"));
+        assert_eq!(failure("ab\nxyw", second), (2, 3, "`xyz`".into()));
+        // Input shorter than the literal fails at its end
+        assert_eq!(failure("mo", literal("move")), (1, 3, "`move`".into()));
+    }
+
+    #[test]
+    fn or_merges_expectations() {
+        let operand = || or(map(literal("old"), |_| 0), int::<i32>());
+        assert_eq!(finish("old", operand()), Ok(0));
+        assert_eq!(finish("-4", operand()), Ok(-4));
+        assert_eq!(
+            failure("x", operand()),
+            (1, 1, "`old` or an integer".into())
+        );
+    }
+
+    #[test]
+    fn or_prefers_the_furthest_failure() {
+        let parser = || {
+            or(
+                pair(literal("ab"), literal("c")),
+                pair(literal("x"), literal("y")),
+            )
+        };
+        assert_eq!(failure("abd", parser()), (1, 3, "`c`".into()));
+        assert_eq!(failure("xz", parser()), (1, 2, "`y`".into()));
+    }
+
+    #[test]
+    fn separated_lists() {
+        let list = || separated(int::<i32>(), literal(","));
+        assert_eq!(finish("1,-2,3", list()), Ok(vec![1, -2, 3]));
+        assert_eq!(list()(""), Ok((vec![], "")));
+        let brackets = delimited(literal("["), list(), literal("]"));
+        assert_eq!(finish("[]", brackets), Ok(vec![]));
+        // A separator must be followed by an item
+        assert_eq!(failure("1,2,", list()), (1, 5, "an integer".into()));
+        assert_eq!(failure("1,2,]", list()), (1, 5, "an integer".into()));
+    }
+
+    #[test]
+    fn line_framing() {
+        assert_eq!(finish("1\n2\n3\n", lines(int::<i32>())), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            failure("1\n2x\n", lines(int::<i32>())),
+            (2, 2, "the end of the line".into())
+        );
+        // A blank line ends the lines
+        assert_eq!(lines(int::<i32>())("1\n\n2\n"), Ok((vec![1], "\n2\n")));
+    }
+
+    #[test]
+    fn block_framing() {
+        let parsed = finish("1\n2\n\n\n3\n", blocks(lines(int::<i32>())));
+        assert_eq!(parsed, Ok(vec![vec![1, 2], vec![3]]));
+        assert_eq!(
+            failure("1\n2\n", blocks(line(int::<i32>()))),
+            (2, 1, "a blank line".into())
+        );
+        assert_eq!(
+            failure("1\n\n2\nx\n", blocks(lines(int::<i32>()))),
+            (4, 1, "an integer".into())
+        );
+    }
+
+    #[test]
+    fn finish_rejects_leftovers() {
+        assert_eq!(finish("12\n\n", int::<i32>()), Ok(12));
+        assert_eq!(
+            failure("12 34", int::<i32>()),
+            (1, 3, "the end of the input".into())
+        );
+        assert_eq!(
+            failure("12\n34\n", line(int::<i32>())),
+            (2, 1, "the end of the input".into())
+        );
+    }
+
+    #[derive(crate::FromInput, Debug, PartialEq)]
+    #[pattern("move {amount} from {from} to {to}")]
+    struct Movement {
+        amount: u8,
+        from: u8,
+        to: u8,
+        /// Not in the pattern, defaulted
+        note: String,
+    }
+
+    #[derive(crate::FromInput, Debug, PartialEq)]
+    #[pattern("{input} at {value}")]
+    struct Named {
+        input: String,
+        value: i32,
+    }
+
+    #[test]
+    fn derived_parser() {
+        let movement = Movement::from_input("move 3 from 1 to 2\n").unwrap();
+        assert_eq!(
+            movement,
+            Movement {
+                amount: 3,
+                from: 1,
+                to: 2,
+                note: String::new(),
+            }
+        );
+
+        let named = Named::from_input("x at -4").unwrap();
+        assert_eq!(named.input, "x");
+        assert_eq!(named.value, -4);
+    }
+
+    #[test]
+    fn derived_parser_errors() {
+        let err = Movement::from_input("mvoe 3 from 1 to 2").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+        assert_eq!(
+            err.expected.as_deref(),
+            Some("`move <amount> from <from> to <to>`")
+        );
+
+        // Without the following text the field runs to the end of the line
+        let err = Movement::from_input("move 3 form 1 to 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 6, 13));
+        assert_eq!(err.expected.as_deref(), Some("<amount>"));
+
+        let err = Movement::from_input("move 300 from 1 to 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (1, 6, 3));
+    }
+}
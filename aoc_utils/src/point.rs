@@ -0,0 +1,582 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Point or offset on a plane
+///
+/// On grids `x` is the column and `y` the row, growing downward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scale by a factor
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, factor: T) -> Point2<T> {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+macro_rules! signed_point {
+    ($($t:ty),*) => {$(
+        impl Point2<$t> {
+            /// Offsets of the points sharing a side, clockwise from the one above
+            pub const SIDES: [Point2<$t>; 4] = [
+                Point2::new(0, -1),
+                Point2::new(1, 0),
+                Point2::new(0, 1),
+                Point2::new(-1, 0),
+            ];
+
+            /// Offsets of the points sharing a side or a corner, clockwise from the one above
+            pub const AROUND: [Point2<$t>; 8] = [
+                Point2::new(0, -1),
+                Point2::new(1, -1),
+                Point2::new(1, 0),
+                Point2::new(1, 1),
+                Point2::new(0, 1),
+                Point2::new(-1, 1),
+                Point2::new(-1, 0),
+                Point2::new(-1, -1),
+            ];
+
+            /// Distance moving along the axes
+            pub fn manhattan(self, other: Point2<$t>) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            /// Distance moving along the axes and the diagonals
+            pub fn chebyshev(self, other: Point2<$t>) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// Sign of each coordinate, a step of at most one in each axis
+            pub fn signum(self) -> Point2<$t> {
+                Point2::new(self.x.signum(), self.y.signum())
+            }
+
+            /// Move a single step towards `target`, diagonally if needed
+            pub fn step_towards(self, target: Point2<$t>) -> Point2<$t> {
+                self + (target - self).signum()
+            }
+
+            /// Points sharing a side
+            pub fn neighbours4(self) -> impl Iterator<Item = Point2<$t>> {
+                Self::SIDES.into_iter().map(move |offset| self + offset)
+            }
+
+            /// Points sharing a side or a corner
+            pub fn neighbours8(self) -> impl Iterator<Item = Point2<$t>> {
+                Self::AROUND.into_iter().map(move |offset| self + offset)
+            }
+        }
+    )*};
+}
+
+signed_point!(i32, i64, isize);
+
+/// One of the four directions on a grid, `Up` is towards smaller `y`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Offset of a single step in this direction
+    pub fn step<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+/// Reads `U`, `R`, `D`, `L` as well as `N`, `E`, `S`, `W`
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        match s {
+            "U" | "N" => Ok(Direction::Up),
+            "R" | "E" => Ok(Direction::Right),
+            "D" | "S" => Ok(Direction::Down),
+            "L" | "W" => Ok(Direction::Left),
+            _ => Err(format!(
+                "expected a direction `U`, `R`, `D`, `L` or `N`, `E`, `S`, `W`, got `{s}`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::Up => "U",
+            Direction::Right => "R",
+            Direction::Down => "D",
+            Direction::Left => "L",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Point or offset in `N` dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+/// Which neighbours of a point to consider, in 3D the cubes sharing a face,
+/// at least an edge or at least a corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Differing on a single axis
+    Face,
+    /// Differing on at most two axes
+    Edge,
+    /// Differing on any amount of axes
+    Corner,
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Point<N> {
+        Point([0; N])
+    }
+}
+
+impl<const N: usize> Point<N> {
+    pub const fn new(coords: [i64; N]) -> Point<N> {
+        Point(coords)
+    }
+
+    /// Distance moving along the axes
+    pub fn manhattan(self, other: Point<N>) -> i64 {
+        (0..N)
+            .map(|axis| (self.0[axis] - other.0[axis]).abs())
+            .sum()
+    }
+
+    /// Distance moving along the axes and every diagonal
+    pub fn chebyshev(self, other: Point<N>) -> i64 {
+        (0..N)
+            .map(|axis| (self.0[axis] - other.0[axis]).abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Sign of each coordinate, a step of at most one in each axis
+    pub fn signum(self) -> Point<N> {
+        Point(self.0.map(i64::signum))
+    }
+
+    /// Points around this one with the given adjacency
+    pub fn neighbours(self, adjacency: Adjacency) -> impl Iterator<Item = Point<N>> {
+        let axes = match adjacency {
+            Adjacency::Face => 1,
+            Adjacency::Edge => 2,
+            Adjacency::Corner => N,
+        };
+        // Every offset in {-1, 0, 1}^N, counting in base 3
+        (0..3usize.pow(N as u32)).filter_map(move |mut idx| {
+            let mut offset = [0; N];
+            for coord in offset.iter_mut() {
+                *coord = (idx % 3) as i64 - 1;
+                idx /= 3;
+            }
+            let moved = offset.iter().filter(|coord| **coord != 0).count();
+            (1..=axes).contains(&moved).then(|| self + Point(offset))
+        })
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|axis| self.0[axis] - other.0[axis]))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        *self = *self - other;
+    }
+}
+
+/// Scale by a factor
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, factor: i64) -> Point<N> {
+        Point(self.0.map(|coord| coord * factor))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Point<N> {
+        Point(self.0.map(|coord| -coord))
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (axis, coord) in self.0.iter().enumerate() {
+            if axis > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{coord}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Point2<i64>> for Point<2> {
+    fn from(point: Point2<i64>) -> Point<2> {
+        Point([point.x, point.y])
+    }
+}
+
+impl From<Point<2>> for Point2<i64> {
+    fn from(Point([x, y]): Point<2>) -> Point2<i64> {
+        Point2::new(x, y)
+    }
+}
+
+/// Smallest box holding a set of points, bounds included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    /// Box holding a single point
+    pub fn new(point: Point<N>) -> BoundingBox<N> {
+        BoundingBox {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Box holding all the `points`, `None` when there are none
+    pub fn from_points(points: impl IntoIterator<Item = Point<N>>) -> Option<BoundingBox<N>> {
+        let mut points = points.into_iter();
+        let first = BoundingBox::new(points.next()?);
+        Some(points.fold(first, BoundingBox::include))
+    }
+
+    /// Grow the box to hold `point`
+    pub fn include(self, point: Point<N>) -> BoundingBox<N> {
+        BoundingBox {
+            min: Point(std::array::from_fn(|axis| self.min[axis].min(point[axis]))),
+            max: Point(std::array::from_fn(|axis| self.max[axis].max(point[axis]))),
+        }
+    }
+
+    pub fn contains(&self, point: Point<N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// Amount of points on each axis
+    pub fn size(&self) -> Point<N> {
+        Point(std::array::from_fn(|axis| {
+            self.max[axis] - self.min[axis] + 1
+        }))
+    }
+
+    /// Amount of points in the box
+    pub fn volume(&self) -> i64 {
+        self.size().0.iter().product()
+    }
+
+    /// The same box with `margin` more points on every side
+    pub fn grow(&self, margin: i64) -> BoundingBox<N> {
+        BoundingBox {
+            min: Point(self.min.0.map(|coord| coord - margin)),
+            max: Point(self.max.0.map(|coord| coord + margin)),
+        }
+    }
+
+    /// Every point of the box, the first axis changing fastest
+    pub fn points(&self) -> impl Iterator<Item = Point<N>> {
+        let (min, size) = (self.min, self.size());
+        (0..self.volume()).map(move |mut idx| {
+            let mut point = min;
+            for axis in 0..N {
+                point[axis] += idx % size[axis];
+                idx /= size[axis];
+            }
+            point
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point2_arithmetic() {
+        let mut a = Point2::new(3i64, -2);
+        let b = Point2::new(-1, 5);
+        assert_eq!(a + b, Point2::new(2, 3));
+        assert_eq!(a - b, Point2::new(4, -7));
+        assert_eq!(a * 3, Point2::new(9, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        a += b;
+        assert_eq!(a, Point2::new(2, 3));
+        a -= b * 2;
+        assert_eq!(a, Point2::new(4, -7));
+        assert_eq!(a.to_string(), "4,-7");
+    }
+
+    #[test]
+    fn point2_distances() {
+        let a = Point2::new(1i32, 1);
+        let b = Point2::new(4, -3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, -1));
+        assert_eq!(Point2::new(0i32, -9).signum(), Point2::new(0, -1));
+    }
+
+    #[test]
+    fn step_towards() {
+        let target = Point2::new(3i64, 1);
+        assert_eq!(Point2::new(0i64, 0).step_towards(target), Point2::new(1, 1));
+        assert_eq!(Point2::new(0i64, 1).step_towards(target), Point2::new(1, 1));
+        assert_eq!(target.step_towards(target), target);
+
+        let mut point = Point2::new(-2i64, 4);
+        let mut steps = 0;
+        while point != target {
+            point = point.step_towards(target);
+            steps += 1;
+        }
+        assert_eq!(steps, Point2::new(-2i64, 4).chebyshev(target));
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = Point2::new(5isize, 5);
+        let sides: Vec<_> = point.neighbours4().collect();
+        assert_eq!(
+            sides,
+            [
+                Point2::new(5, 4),
+                Point2::new(6, 5),
+                Point2::new(5, 6),
+                Point2::new(4, 5)
+            ]
+        );
+        let around: Vec<_> = point.neighbours8().collect();
+        assert_eq!(around.len(), 8);
+        assert!(around.iter().all(|other| point.chebyshev(*other) == 1));
+        assert!(sides.iter().all(|side| around.contains(side)));
+    }
+
+    #[test]
+    fn direction_parsing() {
+        let parse = |s: &str| s.parse::<Direction>();
+        assert_eq!(parse("U"), Ok(Direction::Up));
+        assert_eq!(parse("N"), Ok(Direction::Up));
+        assert_eq!(parse("R"), Ok(Direction::Right));
+        assert_eq!(parse("E"), Ok(Direction::Right));
+        assert_eq!(parse("D"), Ok(Direction::Down));
+        assert_eq!(parse("S"), Ok(Direction::Down));
+        assert_eq!(parse("L"), Ok(Direction::Left));
+        assert_eq!(parse("W"), Ok(Direction::Left));
+        assert!(parse("u").is_err() && parse("X").is_err() && parse("").is_err());
+        for direction in Direction::ALL {
+            assert_eq!(parse(&direction.to_string()), Ok(direction));
+        }
+    }
+
+    #[test]
+    fn direction_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            let step: Point2<i64> = direction.step();
+            assert_eq!(direction.reverse().step::<i64>(), -step);
+        }
+        assert_eq!(Direction::Up.step::<i32>(), Point2::new(0, -1));
+        assert_eq!(Direction::Right.step::<i64>(), Point2::new(1, 0));
+    }
+
+    #[test]
+    fn neighbour_counts() {
+        let origin = Point::<3>::default();
+        assert_eq!(origin.neighbours(Adjacency::Face).count(), 6);
+        assert_eq!(origin.neighbours(Adjacency::Edge).count(), 18);
+        assert_eq!(origin.neighbours(Adjacency::Corner).count(), 26);
+        assert_eq!(Point::new([0, 0]).neighbours(Adjacency::Face).count(), 4);
+        assert_eq!(Point::new([0, 0]).neighbours(Adjacency::Corner).count(), 8);
+        assert_eq!(Point::new([0; 4]).neighbours(Adjacency::Corner).count(), 80);
+
+        let point = Point::new([1, 2, 3]);
+        let faces: Vec<_> = point.neighbours(Adjacency::Face).collect();
+        assert!(faces.iter().all(|other| point.manhattan(*other) == 1));
+        assert!(faces.contains(&Point::new([1, 2, 4])));
+        assert!(point
+            .neighbours(Adjacency::Corner)
+            .all(|other| point.chebyshev(other) == 1 && other != point));
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new([1, -2, 3]);
+        let b = Point::new([4, 0, -1]);
+        assert_eq!(a + b, Point::new([5, -2, 2]));
+        assert_eq!(a - b, Point::new([-3, -2, 4]));
+        assert_eq!(-a * 2, Point::new([-2, 4, -6]));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point::new([1, 1, -1]));
+        assert_eq!(a.to_string(), "1,-2,3");
+        assert_eq!(Point2::from(Point::new([7, 8])), Point2::new(7, 8));
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point::new([2, 5]), Point::new([-1, 3]), Point::new([0, 7])];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point::new([-1, 3]));
+        assert_eq!(bounds.max, Point::new([2, 7]));
+        assert_eq!(bounds.size(), Point::new([4, 5]));
+        assert_eq!(bounds.volume(), 20);
+        assert!(points.iter().all(|point| bounds.contains(*point)));
+        assert!(!bounds.contains(Point::new([3, 3])));
+        assert_eq!(BoundingBox::<2>::from_points([]), None);
+
+        let grown = bounds.include(Point::new([5, 0]));
+        assert_eq!(
+            (grown.min, grown.max),
+            (Point::new([-1, 0]), Point::new([5, 7]))
+        );
+        assert_eq!(bounds.grow(1).volume(), 6 * 7);
+
+        let cube = BoundingBox::new(Point::new([0, 0, 0])).include(Point::new([1, 2, 1]));
+        let inside: Vec<_> = cube.points().collect();
+        assert_eq!(inside.len() as i64, cube.volume());
+        assert_eq!(inside[0], Point::new([0, 0, 0]));
+        assert_eq!(inside[1], Point::new([1, 0, 0]));
+        assert_eq!(inside[2], Point::new([0, 1, 0]));
+        assert_eq!(*inside.last().unwrap(), cube.max);
+        assert!(inside.iter().all(|point| cube.contains(*point)));
+    }
+}
@@ -0,0 +1,272 @@
+//! Shortest path searches over any graph given by a successor function
+//!
+//! Every search starts from one or more nodes and stops at the first node
+//! matching the goal predicate, returning the path found to it.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Shortest path found by a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Total cost of the path, the amount of steps for [`bfs`]
+    pub cost: C,
+    /// Nodes of the path, from the start to the goal both included
+    pub nodes: Vec<N>,
+}
+
+/// Follow the `parents` links from the node at `index` back to a start
+fn reconstruct<N: Clone>(nodes: &[(N, Option<usize>)], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].0.clone()];
+    while let Some(parent) = nodes[index].1 {
+        path.push(nodes[parent].0.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth first search, every step costs 1
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // Every node seen with the index of its parent, the queue holds indexes
+    let mut nodes: Vec<(N, Option<usize>)> = Vec::new();
+    let mut seen: HashMap<N, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push((start, None));
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let node = nodes[index].0.clone();
+        if is_goal(&node) {
+            let nodes = reconstruct(&nodes, index);
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(nodes.len());
+                queue.push_back(nodes.len());
+                nodes.push((next, Some(index)));
+            }
+        }
+    }
+    None
+}
+
+/// Node waiting in the A* frontier, the smallest estimate comes out first
+struct Frontier<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Frontier<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Frontier<C> {}
+
+impl<C: Ord> PartialOrd for Frontier<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Frontier<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for the max heap, ties go to the node furthest from the start
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// A* search, `successors` gives the next nodes with the cost to reach them
+///
+/// `heuristic` must never overestimate the cost left to reach a goal, with
+/// a heuristic of zero this is Dijkstra's algorithm.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Every node seen with its parent and the best cost found to reach it
+    let mut nodes: Vec<(N, Option<usize>)> = Vec::new();
+    let mut costs: Vec<C> = Vec::new();
+    let mut seen: HashMap<N, usize> = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.entry(start.clone()) {
+            entry.insert(nodes.len());
+            frontier.push(Frontier {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                index: nodes.len(),
+            });
+            nodes.push((start, None));
+            costs.push(C::default());
+        }
+    }
+
+    while let Some(Frontier { cost, index, .. }) = frontier.pop() {
+        // Skip nodes reached again by a cheaper path since they were queued
+        if cost > costs[index] {
+            continue;
+        }
+        let node = nodes[index].0.clone();
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&nodes, index),
+            });
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let next_index = match seen.entry(next.clone()) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if costs[next_index] <= next_cost {
+                        continue;
+                    }
+                    nodes[next_index].1 = Some(index);
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(nodes.len());
+                    nodes.push((next.clone(), Some(index)));
+                    costs.push(next_cost);
+                    nodes.len() - 1
+                }
+            };
+            frontier.push(Frontier {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, `successors` gives the next nodes with the cost to reach them
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directed graph where `a` reaches `b` directly at a high cost, and cheaply through `c`
+    const EDGES: &[(char, char, u32)] = &[
+        ('a', 'b', 10),
+        ('a', 'c', 1),
+        ('c', 'b', 1),
+        ('b', 'd', 1),
+        ('c', 'e', 5),
+        ('e', 'd', 1),
+        ('f', 'e', 1),
+    ];
+
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter(|(from, _, _)| from == node)
+            .map(|(_, to, cost)| (*to, *cost))
+            .collect()
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(to, _)| to).collect()
+    }
+
+    #[test]
+    fn bfs_fewest_steps() {
+        let path = bfs(['a'], unweighted, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, ['a', 'b', 'd']);
+        assert_eq!(bfs(['d'], unweighted, |node| *node == 'a'), None);
+    }
+
+    #[test]
+    fn bfs_from_the_closest_start() {
+        let path = bfs(['a', 'f'], unweighted, |node| *node == 'e').unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, ['f', 'e']);
+
+        let path = bfs(['d', 'a'], unweighted, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.nodes, ['d']);
+    }
+
+    #[test]
+    fn dijkstra_reopens_cheaper_paths() {
+        // `b` is first reached straight from `a`, then again for less through `c`
+        let path = dijkstra(['a'], weighted, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, ['a', 'c', 'b', 'd']);
+    }
+
+    #[test]
+    fn astar_with_a_heuristic() {
+        // Lower bounds of the cost left to reach `d`
+        let heuristic = |node: &char| match node {
+            'a' => 3,
+            'b' | 'e' => 1,
+            'c' => 2,
+            _ => 0,
+        };
+        let path = astar(['a'], weighted, heuristic, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, ['a', 'c', 'b', 'd']);
+    }
+
+    #[test]
+    fn dijkstra_from_the_cheapest_start() {
+        let path = dijkstra(['a', 'f'], weighted, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, ['f', 'e', 'd']);
+
+        let path = astar(['f', 'a'], weighted, |_| 0, |node| *node == 'b').unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, ['a', 'c', 'b']);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{Answer, AocError, Input};
+
+/// A puzzle solution, split in a parsing phase shared by both parts
+/// and one solving phase for each part
+pub trait Solution {
+    /// Parsed representation of the puzzle input, it can borrow from the input text
+    type Input<'a>;
+    /// Answer type of the first part
+    type Answer1: Into<Answer>;
+    /// Answer type of the second part
+    type Answer2: Into<Answer>;
+
+    /// Parse the raw puzzle input, reporting where it is malformed
+    /// # Arguments
+    /// * 'input' - Content of the input file
+    fn parse<'a>(input: &'a Input) -> Result<Self::Input<'a>, AocError>;
+
+    /// Solve the first part of the puzzle
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+
+    /// Solve the second part of the puzzle
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
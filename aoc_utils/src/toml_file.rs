@@ -0,0 +1,30 @@
+//! Helpers reading the TOML files kept next to the inputs
+
+use std::{fmt, fs, io, path::Path};
+
+/// Error about the content of the file at `path`
+pub fn invalid_data(path: &Path, msg: impl fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {msg}", path.display()),
+    )
+}
+
+/// Read a TOML file, a missing file reads as `None`
+pub fn read_table(path: &Path) -> io::Result<Option<toml::Table>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let table = content.parse().map_err(|err| invalid_data(path, err))?;
+    Ok(Some(table))
+}
+
+/// Check that a table only uses the expected keys
+pub fn check_keys(path: &Path, table: &toml::Table, keys: &[&str]) -> io::Result<()> {
+    match table.keys().find(|key| !keys.contains(&key.as_str())) {
+        Some(key) => Err(invalid_data(path, format!("unexpected key {key}"))),
+        None => Ok(()),
+    }
+}